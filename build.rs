@@ -0,0 +1,30 @@
+//! Passes the configured event year to the compiler as `AOC_YEAR`. Solutions without a year in
+//! their name (e.g. `src/bin/01.rs`) belong to this year.
+use std::{env, fs};
+
+fn main() {
+    println!("cargo::rerun-if-changed=aoc.toml");
+    println!("cargo::rerun-if-env-changed=AOC_YEAR");
+
+    let year = env::var("AOC_YEAR").ok().or_else(|| {
+        fs::read_to_string("aoc.toml")
+            .ok()
+            .and_then(|config| config_year(&config))
+    });
+
+    if let Some(year) = year {
+        println!("cargo::rustc-env=AOC_YEAR={year}");
+    }
+}
+
+/// The value of the top-level `year` key, i.e. the one in front of the first table.
+fn config_year(config: &str) -> Option<String> {
+    config
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or_default().trim())
+        .take_while(|line| !line.starts_with('['))
+        .find_map(|line| {
+            let (key, value) = line.split_once('=')?;
+            (key.trim() == "year").then(|| value.trim().to_string())
+        })
+}
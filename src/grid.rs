@@ -0,0 +1,467 @@
+//! A two-dimensional grid, as used by many puzzles with maps of characters or digits.
+use std::{
+    error::Error,
+    fmt::Display,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+/// A position in a [`Grid`] as `(row, col)`, with `(0, 0)` in the top left corner.
+pub type Position = (usize, usize);
+
+/// Offsets of the orthogonal neighbours: up, right, down, left.
+pub const DIRECTIONS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the orthogonal and diagonal neighbours, clockwise starting up.
+pub const DIRECTIONS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row in a single `Vec`.
+///
+/// # Display
+/// A grid of displayable cells displays one line per row, e.g. the grid it was parsed from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; rows * cols],
+            rows,
+            cols,
+        }
+    }
+
+    /// Creates a grid from its cells in row order. Returns [`None`] if the number of cells does
+    /// not match the dimensions.
+    pub fn from_vec(rows: usize, cols: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == rows * cols).then_some(Self { cells, rows, cols })
+    }
+
+    /// Parses a grid with one row per line, converting each character with `f`. Trailing blank
+    /// lines are ignored, an empty input is an empty grid.
+    pub fn parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseGridError> {
+        let mut cells = vec![];
+        let mut rows = 0;
+        let mut cols = None;
+
+        for (row, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let before = cells.len();
+
+            for (col, c) in line.chars().enumerate() {
+                cells.push(f(c).ok_or(ParseGridError::InvalidCell(row, col, c))?);
+            }
+
+            let len = cells.len() - before;
+            match cols {
+                Some(cols) if cols != len => return Err(ParseGridError::RaggedRow(row)),
+                _ => cols = Some(len),
+            }
+            rows += 1;
+        }
+
+        Ok(Self {
+            cells,
+            rows,
+            cols: cols.unwrap_or_default(),
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.rows && col < self.cols
+    }
+
+    /// Returns the cell at `position`, or [`None`] if it is outside of the grid.
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Moves `position` by an offset like the ones of [`DIRECTIONS_4`], returns [`None`] if the
+    /// result is outside of the grid.
+    pub fn offset(&self, (row, col): Position, (d_row, d_col): (isize, isize)) -> Option<Position> {
+        let position = (
+            row.checked_add_signed(d_row)?,
+            col.checked_add_signed(d_col)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// The orthogonal neighbours of a position that are inside of the grid.
+    pub fn neighbours_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_4
+            .iter()
+            .filter_map(move |d| self.offset(position, *d))
+    }
+
+    /// The orthogonal and diagonal neighbours of a position that are inside of the grid.
+    pub fn neighbours_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        DIRECTIONS_8
+            .iter()
+            .filter_map(move |d| self.offset(position, *d))
+    }
+
+    /// All positions of the grid in row order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |i| (i / cols, i % cols))
+    }
+
+    /// All cells of the grid with their positions, in row order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The position of the first cell in row order that matches the predicate.
+    pub fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        let index = self.cells.iter().position(predicate)?;
+        Some((index / self.cols, index % self.cols))
+    }
+
+    /// The position of the first cell in row order that equals `value`, e.g. the start of a maze.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.position(|cell| cell == value)
+    }
+
+    /// The cells of a row, panics if the row is outside of the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// The cells of a column from top to bottom, panics if the column is outside of the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.cols, "column {col} is outside of the grid");
+        self.cells.iter().skip(col).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(|col| self.column(col))
+    }
+
+    /// Converts every cell, keeping the dimensions.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            rows: self.rows,
+            cols: self.cols,
+        }
+    }
+
+    /// Mirrors the grid along its main diagonal, its rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols, self.rows, |(row, col)| (col, row))
+    }
+
+    /// Rotates the grid by 90 degrees clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols, self.rows, |(row, col)| {
+            (self.rows - 1 - col, row)
+        })
+    }
+
+    /// Rotates the grid by 90 degrees counter-clockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.cols, self.rows, |(row, col)| {
+            (col, self.cols - 1 - row)
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.rows, self.cols, |(row, col)| {
+            (row, self.cols - 1 - col)
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rebuild(self.rows, self.cols, |(row, col)| {
+            (self.rows - 1 - row, col)
+        })
+    }
+
+    /// Creates a grid of the given dimensions, taking each cell from the position of this grid
+    /// that `source` maps it to.
+    fn rebuild(&self, rows: usize, cols: usize, source: impl Fn(Position) -> Position) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..rows * cols)
+            .map(|i| self[source((i / cols, i % cols))].clone())
+            .collect();
+        Self { cells, rows, cols }
+    }
+
+    fn index(&self, (row, col): Position) -> usize {
+        row * self.cols + col
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    /// Returns the cell at a position, panics if it is outside of the grid.
+    fn index(&self, position: Position) -> &Self::Output {
+        assert!(
+            self.contains(position),
+            "position {position:?} is outside of the grid"
+        );
+        &self.cells[Grid::index(self, position)]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        assert!(
+            self.contains(position),
+            "position {position:?} is outside of the grid"
+        );
+        let index = Grid::index(self, position);
+        &mut self.cells[index]
+    }
+}
+
+impl Grid<u8> {
+    /// Renders a grid of ASCII characters as the text it was parsed from. Its [`Display`] shows
+    /// the byte values instead.
+    pub fn render(&self) -> String {
+        self.iter_rows()
+            .map(|row| row.iter().map(|&b| char::from(b)).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Displays the cells of each row next to each other, one row per line. A [`Grid<char>`]
+/// displays as the text it was parsed from, use [`Grid::render`] for a [`Grid<u8>`].
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Grid<char> {
+    type Err = ParseGridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, Some)
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = ParseGridError;
+
+    /// Parses a grid of ASCII characters as bytes, e.g. `b'#'`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |c| u8::try_from(c).ok())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseGridError {
+    /// A row with a different length than the first one.
+    RaggedRow(usize),
+    /// A character that could not be converted, at `(row, col)`.
+    InvalidCell(usize, usize, char),
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::RaggedRow(row) => {
+                write!(f, "row {row} differs in length from the first row.")
+            }
+            ParseGridError::InvalidCell(row, col, c) => {
+                write!(f, "invalid cell `{c}` at row {row}, column {col}.")
+            }
+        }
+    }
+}
+
+impl Error for ParseGridError {}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Grid, ParseGridError};
+
+    const INPUT: &str = "abc\ndef";
+
+    fn grid() -> Grid<char> {
+        INPUT.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_grids() {
+        let grid = grid();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.to_string(), INPUT);
+
+        let digits = Grid::parse_with("12\n34\n", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits[(1, 0)], 3);
+
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(ParseGridError::RaggedRow(1))
+        );
+        assert_eq!(
+            Grid::parse_with("1x", |c| c.to_digit(10)),
+            Err(ParseGridError::InvalidCell(0, 1, 'x'))
+        );
+
+        let padded: Grid<char> = "ab\ncd\n\n".parse().unwrap();
+        assert_eq!((padded.rows(), padded.cols()), (2, 2));
+
+        let empty: Grid<u8> = "".parse().unwrap();
+        assert_eq!(
+            (empty.rows(), empty.cols(), empty.iter_rows().count()),
+            (0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn indexes_cells() {
+        let mut grid = grid();
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+
+        grid[(0, 0)] = 'x';
+        *grid.get_mut((1, 1)).unwrap() = 'y';
+        assert_eq!(grid.to_string(), "xbc\ndyf");
+
+        assert_eq!(grid.find(&'y'), Some((1, 1)));
+        assert_eq!(grid.position(|c| *c > 'c' && *c < 'x'), Some((1, 0)));
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_unchecked_index_outside_of_grid() {
+        let _ = grid()[(0, 3)];
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours_4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(
+            grid.neighbours_4((1, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 2), (1, 0)]
+        );
+        assert_eq!(grid.neighbours_8((0, 1)).count(), 5);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(
+            grid.iter_columns()
+                .map(|c| c.collect::<String>())
+                .collect::<Vec<_>>(),
+            vec!["ad", "be", "cf"]
+        );
+        assert_eq!(grid.iter_rows().count(), 2);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+        assert_eq!(grid.positions().last(), Some((1, 2)));
+    }
+
+    #[test]
+    fn transforms_grids() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.map(|c| c.is_ascii_lowercase()).find(&false), None);
+    }
+
+    #[test]
+    fn renders_byte_grids_as_text() {
+        let input = "#.#\n.S.\n";
+        let grid: Grid<u8> = input.parse().unwrap();
+        assert_eq!(grid[(1, 1)], b'S');
+        assert_eq!(grid.render(), input.trim_end());
+        assert_eq!(
+            grid.render().parse::<Grid<u8>>().unwrap().render(),
+            grid.render()
+        );
+    }
+}
@@ -1,3 +1,5 @@
+pub mod grid;
+pub mod pathfinding;
 pub mod template;
 
 // Use this file to add helper functions and additional modules.
@@ -1,144 +1,372 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
-use args::{parse, AppArguments};
+use advent_of_code::template::commands::{
+    all, archive_year, check_days, completions, download, leaderboard, privacy_check, read,
+    scaffold, solve, status, time, watch,
+};
+use advent_of_code::template::usage;
+use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
-    use std::process;
+    use advent_of_code::template::{
+        Day, DaySet, PuzzleId, Year,
+        commands::{
+            completions::Shell,
+            scaffold::{self, Overwrite},
+        },
+        usage::{self, Command, UsageError},
+    };
 
     pub enum AppArguments {
+        CheckDays {
+            days: DaySet,
+        },
+        Completions {
+            shell: Shell,
+        },
         Download {
-            day: Day,
+            days: DaySet,
+            force: bool,
+        },
+        Leaderboard {
+            year: Year,
+            id: u64,
+            day: Option<Day>,
+            fixture: Option<String>,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+            page: bool,
         },
         Scaffold {
-            day: Day,
+            days: DaySet,
             download: bool,
-            overwrite: bool,
+            template: Option<String>,
+            overwrite: Overwrite,
+            dry_run: bool,
+        },
+        Status {
+            year: Year,
+        },
+        PrivacyCheck {
+            fix: bool,
+        },
+        ArchiveYear {
+            year: Year,
+            next: Year,
+            dry_run: bool,
         },
         Solve {
-            day: Day,
+            days: DaySet,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            force: bool,
+            watch: bool,
+            tests: bool,
         },
         All {
+            days: DaySet,
             release: bool,
         },
         Time {
+            year: Year,
             all: bool,
-            day: Option<Day>,
+            days: Option<DaySet>,
             store: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+        /// Prints the help of a command, or lists all commands.
+        Help {
+            command: Option<&'static Command>,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
-        let mut args = pico_args::Arguments::from_env();
+        let mut raw_args: Vec<String> = std::env::args().skip(1).collect();
+        if raw_args.first().is_some_and(|arg| arg == "scaffold") {
+            scaffold::attach_overwrite_value(&mut raw_args);
+        }
+        let mut args = pico_args::Arguments::from_vec(raw_args.iter().map(Into::into).collect());
 
-        let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-            },
+        let subcommand = args.subcommand()?;
+        let is_help = args.contains(["-h", "--help"]);
+
+        let command = match subcommand.as_deref() {
+            Some(name) => {
+                usage::find_command(name).ok_or_else(|| UsageError::UnknownCommand(name.into()))?
+            }
+            None if is_help => return Ok(AppArguments::Help { command: None }),
+            None => return Err(UsageError::MissingCommand.into()),
+        };
+
+        if is_help {
+            return Ok(AppArguments::Help {
+                command: Some(command),
+            });
+        }
+
+        usage::validate(command, &raw_args[1..])?;
+
+        let year: Option<Year> = args.opt_value_from_str("--year")?;
+
+        let app_args = match subcommand.as_deref() {
+            Some("all") => {
+                let release = args.contains("--release");
+
+                AppArguments::All {
+                    days: opt_free_days(&mut args, year)?
+                        .unwrap_or_else(|| DaySet::all(year.unwrap_or_else(Year::configured))),
+                    release,
+                }
+            }
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let days = opt_free_days(&mut args, year)?;
 
                 AppArguments::Time {
+                    year: days
+                        .as_ref()
+                        .map_or_else(|| year.unwrap_or_else(Year::configured), DaySet::year),
                     all,
-                    day: args.opt_free_from_str()?,
+                    days,
                     store,
                 }
             }
-            Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+            Some("download") => {
+                let force = args.contains("--force");
+
+                let days = if args.contains("--all") {
+                    DaySet::all(year.unwrap_or_else(Year::configured))
+                } else {
+                    free_days(&mut args, year)?
+                };
+
+                AppArguments::Download { days, force }
+            }
+            Some("leaderboard") => AppArguments::Leaderboard {
+                year: year.unwrap_or_else(Year::configured),
+                day: args.opt_value_from_str("--day")?,
+                fixture: args.opt_value_from_str("--fixture")?,
+                id: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                page: args.contains("--page"),
+                puzzle: free_puzzle(&mut args, year)?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
+            Some("scaffold") => {
+                let download = args.contains("--download");
+                let dry_run = args.contains("--dry-run");
+                let template = args.opt_value_from_str("--template")?;
+                // a bare `--overwrite` keeps selecting the module only.
+                let overwrite = if args.contains("--overwrite") {
+                    Overwrite::MODULE
+                } else {
+                    args.opt_value_from_str("--overwrite")?.unwrap_or_default()
+                };
+
+                AppArguments::Scaffold {
+                    days: free_days(&mut args, year)?,
+                    download,
+                    template,
+                    overwrite,
+                    dry_run,
+                }
+            }
+            Some("solve") => {
+                let release = args.contains("--release");
+                let submit: Option<u8> = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let force = args.contains("--force");
+                let watch = args.contains("--watch");
+                let tests = args.contains("--tests");
+                let days = free_days(&mut args, year)?;
+
+                if submit.is_some() && days.single().is_none() {
+                    return Err(UsageError::InvalidCombination(
+                        "`--submit` can only be used when solving a single day.",
+                    )
+                    .into());
+                }
+
+                if watch && (days.single().is_none() || submit.is_some() || dhat) {
+                    return Err(UsageError::InvalidCombination(
+                        "`--watch` can only be used when solving a single day without `--submit` or `--dhat`.",
+                    )
+                    .into());
+                }
+
+                if tests && !watch {
+                    return Err(UsageError::InvalidCombination(
+                        "`--tests` can only be used together with `--watch`.",
+                    )
+                    .into());
+                }
+
+                AppArguments::Solve {
+                    days,
+                    release,
+                    dhat,
+                    submit,
+                    force,
+                    watch,
+                    tests,
+                }
+            }
+            #[cfg(feature = "today")]
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("status") => AppArguments::Status {
+                year: year.unwrap_or_else(Year::configured),
             },
-            #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
-            Some(x) => {
-                eprintln!("Unknown command: {x}");
-                process::exit(1);
-            }
-            None => {
-                eprintln!("No command specified.");
-                process::exit(1);
+            Some("privacy-check") => AppArguments::PrivacyCheck {
+                fix: args.contains("--fix"),
+            },
+            Some("archive-year") => {
+                let year = year.unwrap_or_else(Year::configured);
+                let next: Option<Year> = args.opt_value_from_str("--next")?;
+
+                AppArguments::ArchiveYear {
+                    year,
+                    next: next
+                        .or_else(|| Year::new(year.into_inner() + 1))
+                        .unwrap_or(year),
+                    dry_run: args.contains("--dry-run"),
+                }
             }
+            Some("check-days") => AppArguments::CheckDays {
+                days: opt_free_days(&mut args, year)?
+                    .unwrap_or_else(|| DaySet::all(year.unwrap_or_else(Year::configured))),
+            },
+            Some("completions") => AppArguments::Completions {
+                shell: args.free_from_str()?,
+            },
+            Some("help") => AppArguments::Help {
+                command: args
+                    .opt_free_from_str::<String>()?
+                    .map(|name| usage::find_command(&name).ok_or(UsageError::UnknownCommand(name)))
+                    .transpose()?,
+            },
+            // all commands of the usage are handled above.
+            _ => unreachable!(),
         };
 
-        let remaining = args.finish();
-        if !remaining.is_empty() {
-            eprintln!("Warning: unknown argument(s): {remaining:?}.");
+        if let Some(arg) = args.finish().first() {
+            return Err(UsageError::UnexpectedArgument(arg.to_string_lossy().into()).into());
         }
 
         Ok(app_args)
     }
+
+    /// Parses a puzzle like `5` or `2024/05`, plain days belong to the `--year` if provided.
+    fn free_puzzle(
+        args: &mut pico_args::Arguments,
+        year: Option<Year>,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let value: String = args.free_from_str()?;
+        Ok(PuzzleId::parse_with_year(&value, year)?)
+    }
+
+    /// Parses a selection of days like `1-5,8,10-`, see [`DaySet`].
+    fn free_days(
+        args: &mut pico_args::Arguments,
+        year: Option<Year>,
+    ) -> Result<DaySet, Box<dyn std::error::Error>> {
+        let value: String = args.free_from_str()?;
+        Ok(DaySet::parse_with_year(&value, year)?)
+    }
+
+    fn opt_free_days(
+        args: &mut pico_args::Arguments,
+        year: Option<Year>,
+    ) -> Result<Option<DaySet>, Box<dyn std::error::Error>> {
+        let value: Option<String> = args.opt_free_from_str()?;
+        Ok(value
+            .map(|value| DaySet::parse_with_year(&value, year))
+            .transpose()?)
+    }
 }
 
 fn main() {
-    match parse() {
+    let args = match parse() {
+        Ok(args) => args,
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
-        Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
-                day,
-                download,
-                overwrite,
-            } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
-                }
-            }
-            AppArguments::Solve {
-                day,
-                release,
-                dhat,
-                submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+    };
+
+    let result = match args {
+        AppArguments::All { days, release } => all::handle(&days, release),
+        AppArguments::Time {
+            year,
+            days,
+            all,
+            store,
+        } => time::handle(year, days, all, store),
+        AppArguments::Download { days, force } => download::handle(&days, force),
+        AppArguments::Leaderboard {
+            year,
+            id,
+            day,
+            fixture,
+        } => leaderboard::handle(year, id, day, fixture.as_deref()),
+        AppArguments::Read { puzzle, page } => read::handle(puzzle, page),
+        AppArguments::Scaffold {
+            days,
+            download,
+            template,
+            overwrite,
+            dry_run,
+        } => {
+            // the description provides the title of the puzzle to the template.
+            let downloaded = if download && !dry_run {
+                download::handle(&days, false)
+            } else {
+                Ok(())
+            };
+            scaffold::handle(&days, template.as_deref(), overwrite, dry_run).and(downloaded)
+        }
+        AppArguments::Solve {
+            days,
+            release,
+            dhat,
+            submit,
+            force,
+            watch,
+            tests,
+        } => match days.single() {
+            Some(puzzle) if watch => watch::handle(puzzle, release, tests),
+            _ => solve::handle(&days, release, dhat, submit, force),
         },
+        #[cfg(feature = "today")]
+        AppArguments::Today { wait } => today::handle(wait),
+        AppArguments::CheckDays { days } => check_days::handle(&days),
+        AppArguments::Status { year } => status::handle(year),
+        AppArguments::PrivacyCheck { fix } => privacy_check::handle(fix),
+        AppArguments::ArchiveYear {
+            year,
+            next,
+            dry_run,
+        } => archive_year::handle(year, next, dry_run),
+        AppArguments::Completions { shell } => {
+            completions::handle(shell);
+            Ok(())
+        }
+        AppArguments::Help { command } => {
+            print!("{}", command.map_or_else(usage::help, |c| c.help()));
+            Ok(())
+        }
     };
+
+    if let Err(err) = result {
+        eprintln!("Error: {err}");
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,440 @@
+//! Shortest path searches over graphs given by a neighbour function, e.g. the cells of a [`Grid`].
+//!
+//! Every search returns a [`Search`] that holds the distance of each reached node and, to
+//! reconstruct paths, all of its predecessors on shortest paths.
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::grid::{Grid, Position};
+
+/// The result of a search: distances of the reached nodes and their predecessors on shortest paths.
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    distances: HashMap<N, C>,
+    parents: HashMap<N, Vec<N>>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> Search<N, C> {
+    /// The shortest distance to a node, [`None`] if it was not reached.
+    pub fn distance(&self, node: N) -> Option<C> {
+        self.distances.get(&node).copied()
+    }
+
+    /// The shortest distances of all reached nodes, including the starts.
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// A shortest path from a start to `to`, including both. [`None`] if `to` was not reached.
+    pub fn path(&self, to: N) -> Option<Vec<N>> {
+        self.distances.get(&to)?;
+
+        let mut path = vec![to];
+        let mut node = to;
+        while let Some(&parent) = self.parents.get(&node).and_then(|p| p.first()) {
+            path.push(parent);
+            node = parent;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    /// All shortest paths from the starts to `to`. Their number can grow exponentially with the
+    /// length of the paths, prefer [`Search::nodes_on_paths`] if only the visited nodes matter.
+    pub fn paths(&self, to: N) -> Vec<Vec<N>> {
+        if !self.distances.contains_key(&to) {
+            return vec![];
+        }
+
+        let mut paths = vec![];
+        let mut stack = vec![vec![to]];
+
+        while let Some(path) = stack.pop() {
+            let node = path[path.len() - 1];
+            match self.parents.get(&node) {
+                Some(parents) if !parents.is_empty() => {
+                    for &parent in parents {
+                        let mut path = path.clone();
+                        path.push(parent);
+                        stack.push(path);
+                    }
+                }
+                _ => {
+                    let mut path = path;
+                    path.reverse();
+                    paths.push(path);
+                }
+            }
+        }
+
+        paths
+    }
+
+    /// Every node that lies on a shortest path to `to`, including the starts and `to`.
+    pub fn nodes_on_paths(&self, to: N) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        if !self.distances.contains_key(&to) {
+            return nodes;
+        }
+
+        let mut stack = vec![to];
+        while let Some(node) = stack.pop() {
+            if nodes.insert(node) {
+                stack.extend(self.parents.get(&node).into_iter().flatten());
+            }
+        }
+
+        nodes
+    }
+
+    fn add_parent(&mut self, node: N, parent: N) {
+        self.parents.entry(node).or_default().push(parent);
+    }
+}
+
+/// Breadth-first search from `start` where every move costs one step.
+pub fn bfs<N, I>(start: N, neighbours: impl FnMut(N) -> I) -> Search<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    multi_bfs([start], neighbours)
+}
+
+/// Breadth-first search from several starts at once, the distance of a node is the one to its
+/// nearest start.
+pub fn multi_bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+) -> Search<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+    };
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.distances.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = search.distances[&node] + 1;
+
+        for next in neighbours(node) {
+            match search.distances.get(&next) {
+                Some(&d) if d == distance => search.add_parent(next, node),
+                Some(_) => {}
+                None => {
+                    search.distances.insert(next, distance);
+                    search.add_parent(next, node);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+
+    search
+}
+
+/// Breadth-first search over the cells of a grid, moving orthogonally onto cells that are
+/// `passable`.
+pub fn grid_bfs<T>(
+    grid: &Grid<T>,
+    start: Position,
+    passable: impl Fn(&T) -> bool,
+) -> Search<Position, usize> {
+    bfs(start, |position| {
+        grid.neighbours_4(position)
+            .filter(|next| passable(&grid[*next]))
+            .collect::<Vec<_>>()
+    })
+}
+
+/// Dijkstra's algorithm from `start` over moves with non-negative costs, e.g. `(next, 1000)` for
+/// turning and `(next, 1)` for stepping forward. Explores every reachable node, use [`astar`]
+/// with a heuristic of zero to stop at a goal.
+pub fn dijkstra<N, C, I>(start: N, neighbours: impl FnMut(N) -> I) -> Search<N, C>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first([start], neighbours, |_| C::default(), |_| false).1
+}
+
+/// A* search from `start` to the first node that `is_goal` accepts. Returns the goal that was
+/// reached and the search, or [`None`] if no goal is reachable.
+///
+/// The `heuristic` estimates the remaining cost to a goal, e.g. the manhattan distance for
+/// orthogonal moves of cost one. As expanded nodes are never reopened, it has to be consistent:
+/// it must never drop by more than the cost of a move, and be zero at goals. Then the search holds
+/// all shortest paths to the goal.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(N) -> I,
+    heuristic: impl FnMut(N) -> C,
+    is_goal: impl FnMut(N) -> bool,
+) -> Option<(N, Search<N, C>)>
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (goal, search) = best_first([start], neighbours, heuristic, is_goal);
+    Some((goal?, search))
+}
+
+/// A node in the queue of [`best_first`]. Ordered by its estimated total cost, cheapest first.
+struct Entry<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> Ord for Entry<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl<N, C: Ord> PartialOrd for Entry<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> PartialEq for Entry<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.estimate == other.estimate
+    }
+}
+
+impl<N, C: Ord> Eq for Entry<N, C> {}
+
+/// Shared implementation of [`dijkstra`] and [`astar`]. After a goal is reached, nodes with the
+/// same estimate are still expanded so that every shortest path to it is recorded.
+fn best_first<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(N) -> I,
+    mut heuristic: impl FnMut(N) -> C,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> (Option<N>, Search<N, C>)
+where
+    N: Copy + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search {
+        distances: HashMap::new(),
+        parents: HashMap::new(),
+    };
+    // tentative costs of nodes that were queued but not expanded yet.
+    let mut costs: HashMap<N, C> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    let mut goal: Option<(N, C)> = None;
+    let starts: HashSet<N> = starts.into_iter().collect();
+
+    for &node in &starts {
+        costs.insert(node, C::default());
+        queue.push(Entry {
+            estimate: heuristic(node),
+            cost: C::default(),
+            node,
+        });
+    }
+
+    while let Some(Entry {
+        estimate,
+        cost,
+        node,
+    }) = queue.pop()
+    {
+        if goal.is_some_and(|(_, goal_cost)| estimate > goal_cost) {
+            break;
+        }
+        if search.distances.contains_key(&node) || costs[&node] < cost {
+            continue;
+        }
+
+        search.distances.insert(node, cost);
+
+        if goal.is_none() && is_goal(node) {
+            goal = Some((node, cost));
+        }
+        if goal.is_some_and(|(goal, _)| goal == node) {
+            continue;
+        }
+
+        for (next, step) in neighbours(node) {
+            let next_cost = cost + step;
+
+            match costs.get(&next) {
+                Some(&c) if next_cost > c => {}
+                Some(&c) if next_cost == c => {
+                    // zero-cost moves lead back to starts and expanded nodes, which must not
+                    // gain parents as these would form cycles.
+                    if !starts.contains(&next) && !search.distances.contains_key(&next) {
+                        search.add_parent(next, node);
+                    }
+                }
+                _ => {
+                    costs.insert(next, next_cost);
+                    search.parents.insert(next, vec![node]);
+                    queue.push(Entry {
+                        estimate: next_cost + heuristic(next),
+                        cost: next_cost,
+                        node: next,
+                    });
+                }
+            }
+        }
+    }
+
+    // only the parents of expanded nodes are final.
+    search
+        .parents
+        .retain(|node, _| search.distances.contains_key(node));
+
+    (goal.map(|(node, _)| node), search)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{astar, bfs, dijkstra, grid_bfs, multi_bfs};
+    use crate::grid::{Grid, Position};
+
+    const MAZE: &str = "S..#\n.#..\n...E";
+
+    fn maze() -> Grid<char> {
+        MAZE.parse().unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, position: Position) -> Vec<Position> {
+        grid.neighbours_4(position)
+            .filter(|p| grid[*p] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn finds_distances_with_bfs() {
+        let grid = maze();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+
+        let search = bfs(start, |p| open_neighbours(&grid, p));
+        assert_eq!(search.distance(end), Some(5));
+        assert_eq!(search.distance((0, 3)), None);
+        assert_eq!(search.distances().len(), 10);
+
+        let search = grid_bfs(&grid, start, |c| *c != '#');
+        assert_eq!(search.distance(end), Some(5));
+
+        let path = search.path(end).unwrap();
+        assert_eq!(
+            (path.first(), path.last(), path.len()),
+            (Some(&start), Some(&end), 6)
+        );
+    }
+
+    #[test]
+    fn reconstructs_all_shortest_paths() {
+        let grid = maze();
+        let search = grid_bfs(&grid, (0, 0), |c| *c != '#');
+
+        let mut paths = search.paths((2, 3));
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                vec![(0, 0), (0, 1), (0, 2), (1, 2), (1, 3), (2, 3)],
+                vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 3)],
+                vec![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2), (2, 3)],
+            ]
+        );
+        assert_eq!(search.nodes_on_paths((2, 3)).len(), 10);
+        assert!(search.paths((0, 3)).is_empty());
+    }
+
+    #[test]
+    fn uses_nearest_start_with_multi_bfs() {
+        let search = multi_bfs([0, 10], |n: i32| {
+            [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n))
+        });
+        assert_eq!(search.distance(3), Some(3));
+        assert_eq!(search.distance(8), Some(2));
+        assert_eq!(search.path(8), Some(vec![10, 9, 8]));
+    }
+
+    #[test]
+    fn finds_cheapest_paths_with_weighted_moves() {
+        // a -> c directly costs 10, a -> b -> c costs 3.
+        let edges = |n: char| match n {
+            'a' => vec![('b', 1), ('c', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+
+        let search = dijkstra('a', edges);
+        assert_eq!(search.distance('c'), Some(3));
+        assert_eq!(search.distance('d'), Some(4));
+        assert_eq!(search.path('d'), Some(vec!['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn finds_goals_with_astar() {
+        let grid = maze();
+        let end = grid.find(&'E').unwrap();
+        let manhattan = |(r, c): Position| r.abs_diff(end.0) + c.abs_diff(end.1);
+
+        let (goal, search) = astar(
+            (0, 0),
+            |p| open_neighbours(&grid, p).into_iter().map(|n| (n, 1)),
+            manhattan,
+            |p| p == end,
+        )
+        .unwrap();
+
+        assert_eq!(goal, end);
+        assert_eq!(search.distance(end), Some(5));
+        assert_eq!(search.paths(end).len(), 3);
+
+        let unreachable = astar(
+            (0, 0),
+            |p| open_neighbours(&grid, p).into_iter().map(|n| (n, 1)),
+            |_| 0,
+            |p| p == (0, 3),
+        );
+        assert!(unreachable.is_none());
+    }
+
+    #[test]
+    fn handles_zero_cost_cycles() {
+        let search = dijkstra(0, |n: u8| match n {
+            0 => vec![(1, 0)],
+            1 => vec![(0, 0), (2, 1), (3, 0)],
+            3 => vec![(1, 0), (2, 1)],
+            _ => vec![],
+        });
+
+        assert_eq!(search.path(0), Some(vec![0]));
+        assert_eq!(search.path(3), Some(vec![0, 1, 3]));
+        assert_eq!(search.distance(2), Some(1));
+        let mut paths = search.paths(2);
+        paths.sort();
+        assert_eq!(paths, vec![vec![0, 1, 2], vec![0, 1, 3, 2]]);
+    }
+}
@@ -1,25 +1,74 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
+    io::{self, ErrorKind, Write},
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::PuzzleId;
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
-    CommandNotCallable,
-    BadExitStatus(Output),
+    CommandNotCallable(io::Error),
+    SessionNotFound,
+    PuzzleNotAvailable,
+    Http(String),
+    BadExitStatus { code: Option<i32>, stderr: String },
 }
 
 impl Display for AocCommandError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            AocCommandError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-            AocCommandError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-            AocCommandError::BadExitStatus(_) => {
-                write!(f, "aoc-cli exited with a non-zero status.")
+            AocCommandError::CommandNotFound => write!(
+                f,
+                "aoc-cli is not present in environment. Try running \"cargo install aoc-cli\" to install it."
+            ),
+            AocCommandError::CommandNotCallable(e) => write!(f, "aoc-cli could not be called: {e}"),
+            AocCommandError::SessionNotFound => write!(
+                f,
+                "aoc-cli could not find a valid session cookie. Refresh the `.adventofcode.session` file."
+            ),
+            AocCommandError::PuzzleNotAvailable => {
+                write!(f, "the puzzle is not available yet.")
+            }
+            AocCommandError::Http(message) => write!(f, "aoc-cli request failed: {message}"),
+            AocCommandError::BadExitStatus { code, stderr } => {
+                match code {
+                    Some(code) => write!(f, "aoc-cli exited with status {code}.")?,
+                    None => write!(f, "aoc-cli was terminated by a signal.")?,
+                }
+                if !stderr.trim().is_empty() {
+                    write!(f, "\n{}", stderr.trim())?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocCommandError {}
+
+impl AocCommandError {
+    /// Interprets the exit code and error output of a failed aoc-cli call.
+    fn from_failure(code: Option<i32>, stderr: &str) -> Self {
+        let message = stderr.to_lowercase();
+
+        if message.contains("session") {
+            AocCommandError::SessionNotFound
+        } else if message.contains("not available")
+            || message.contains("locked")
+            || message.contains("404")
+        {
+            AocCommandError::PuzzleNotAvailable
+        } else if message.contains("http") {
+            let line = stderr.lines().rev().find(|l| !l.trim().is_empty());
+            AocCommandError::Http(line.unwrap_or_default().trim().to_string())
+        } else {
+            AocCommandError::BadExitStatus {
+                code,
+                stderr: stderr.to_string(),
             }
         }
     }
@@ -29,12 +78,15 @@ pub fn check() -> Result<(), AocCommandError> {
     Command::new("aoc")
         .arg("-V")
         .output()
-        .map_err(|_| AocCommandError::CommandNotFound)?;
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => AocCommandError::CommandNotFound,
+            _ => AocCommandError::CommandNotCallable(e),
+        })?;
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = puzzle.puzzle_path();
 
     let args = build_args(
         "read",
@@ -43,15 +95,19 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        puzzle,
     );
 
-    call_aoc_cli(&args)
+    call_aoc_cli(&args, Stdio::inherit())
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+pub fn download(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let input_path = puzzle.input_path();
+    let puzzle_path = puzzle.puzzle_path();
+
+    // aoc-cli does not create missing parent directories.
+    let _ = fs::create_dir_all(puzzle.data_dir("inputs"));
+    let _ = fs::create_dir_all(puzzle.data_dir("puzzles"));
 
     let args = build_args(
         "download",
@@ -62,64 +118,98 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        puzzle,
     );
 
-    let output = call_aoc_cli(&args)?;
+    let output = call_aoc_cli(&args, Stdio::inherit())?;
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Submits an answer. The output is captured so that the response can be interpreted.
+pub fn submit(puzzle: PuzzleId, part: u8, result: &str) -> Result<Output, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], puzzle);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
-
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
-
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+    call_aoc_cli(&args, Stdio::piped())
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
-}
-
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year().to_string(),
+        "--day".into(),
+        puzzle.day().to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+/// Calls aoc-cli. Its error output is captured to interpret failures and forwarded otherwise.
+fn call_aoc_cli(args: &[String], stdout: Stdio) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
+        .stdout(stdout)
+        .stderr(Stdio::piped())
         .output()
-        .map_err(|_| AocCommandError::CommandNotCallable)?;
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => AocCommandError::CommandNotFound,
+            _ => AocCommandError::CommandNotCallable(e),
+        })?;
 
     if output.status.success() {
+        let _ = io::stderr().write_all(&output.stderr);
         Ok(output)
     } else {
-        Err(AocCommandError::BadExitStatus(output))
+        Err(AocCommandError::from_failure(
+            output.status.code(),
+            &String::from_utf8_lossy(&output.stderr),
+        ))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::AocCommandError;
+
+    #[test]
+    fn interprets_failures() {
+        assert!(matches!(
+            AocCommandError::from_failure(Some(1), "error: Failed to read session cookie"),
+            AocCommandError::SessionNotFound
+        ));
+        assert!(matches!(
+            AocCommandError::from_failure(Some(1), "error: Puzzle 2025/13 is not available"),
+            AocCommandError::PuzzleNotAvailable
+        ));
+        assert!(matches!(
+            AocCommandError::from_failure(Some(1), "[INFO] ...\nerror: HTTP request error: timed out\n"),
+            AocCommandError::Http(message) if message == "error: HTTP request error: timed out"
+        ));
+        assert!(matches!(
+            AocCommandError::from_failure(Some(2), "something else"),
+            AocCommandError::BadExitStatus { code: Some(2), .. }
+        ));
+    }
+
+    #[test]
+    fn shows_error_output() {
+        let error = AocCommandError::BadExitStatus {
+            code: Some(2),
+            stderr: "something else\n".into(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "aoc-cli exited with status 2.\nsomething else"
+        );
     }
 }
@@ -0,0 +1,323 @@
+/// Built-in HTTP client for the advent of code website.
+use std::{
+    env,
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{PuzzleId, Year, config, html};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the client to the advent of code maintainers, as requested in their automation guidelines.
+static USER_AGENT: &str = concat!("advent_of_code/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    Request(String),
+    BadStatus(u16),
+    Write(String),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found in `AOC_SESSION` or `~/.config/adventofcode.session`."
+            ),
+            AocClientError::Request(e) => write!(f, "request failed: {e}"),
+            AocClientError::BadStatus(404) => write!(
+                f,
+                "advent of code responded with status 404. The puzzle might not be unlocked yet."
+            ),
+            AocClientError::BadStatus(status @ (400 | 500)) => write!(
+                f,
+                "advent of code responded with status {status}. The session cookie might be invalid or expired."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "advent of code responded with status {status}.")
+            }
+            AocClientError::Write(path) => write!(f, "could not write \"{path}\"."),
+        }
+    }
+}
+
+impl std::error::Error for AocClientError {}
+
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(&user_agent(config::contact()))
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Creates a client for the base url in `AOC_BASE_URL` (defaults to the advent of code website)
+    /// with the session cookie found by [`find_session`].
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = find_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        Ok(Self::new(&base_url, &session))
+    }
+
+    /// Fetches the personal puzzle input.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.puzzle_url(puzzle)))
+    }
+
+    /// Fetches the puzzle description as markdown.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.puzzle_url(puzzle))?;
+        Ok(html::puzzle_to_markdown(&html))
+    }
+
+    /// Submits an answer and returns the response message as markdown.
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.puzzle_url(puzzle));
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        let html = read_response(response)?;
+        Ok(html::response_to_markdown(&html))
+    }
+
+    /// Fetches the JSON of a private leaderboard.
+    pub fn leaderboard(&self, year: Year, id: u64) -> Result<String, AocClientError> {
+        self.get(&format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        ))
+    }
+
+    /// Writes input and puzzle description to the data folder.
+    pub fn download(&self, puzzle: PuzzleId) -> Result<(), AocClientError> {
+        let input_path = puzzle.input_path();
+        let puzzle_path = puzzle.puzzle_path();
+
+        write_file(&input_path, &self.input(puzzle)?)?;
+        write_file(&puzzle_path, &self.puzzle(puzzle)?)?;
+
+        println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+        println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+        Ok(())
+    }
+
+    /// Fetches the puzzle description and stores it.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<(), AocClientError> {
+        write_file(&puzzle.puzzle_path(), &self.puzzle(puzzle)?)
+    }
+
+    fn puzzle_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year(),
+            puzzle.day().into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        read_response(self.agent.get(url).set("Cookie", &self.cookie()).call())
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| AocClientError::Request(e.to_string())),
+        Err(ureq::Error::Status(status, _)) => Err(AocClientError::BadStatus(status)),
+        Err(e) => Err(AocClientError::Request(e.to_string())),
+    }
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent).map_err(|_| AocClientError::Write(path.into()))?;
+    }
+    fs::write(path, contents).map_err(|_| AocClientError::Write(path.into()))
+}
+
+/// Looks up the session cookie in `AOC_SESSION`, `~/.config/adventofcode.session`
+/// and `~/.adventofcode.session` (the location used by aoc-cli), in that order.
+pub fn find_session() -> Option<String> {
+    if let Ok(session) = env::var("AOC_SESSION")
+        && !session.trim().is_empty()
+    {
+        return Some(session.trim().to_string());
+    }
+
+    let home = PathBuf::from(env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?);
+
+    [
+        home.join(".config").join("adventofcode.session"),
+        home.join(".adventofcode.session"),
+    ]
+    .iter()
+    .filter_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .find(|session| !session.is_empty())
+}
+
+/// The user agent of requests, with the configured contact so the maintainers can reach you.
+fn user_agent(contact: Option<&str>) -> String {
+    match contact {
+        Some(contact) => format!("{USER_AGENT} ({contact})"),
+        None => USER_AGENT.to_string(),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{AocClient, AocClientError, USER_AGENT, user_agent};
+    use crate::{
+        day,
+        template::{PuzzleId, Year},
+    };
+
+    /// Serves a single request with the provided status and body, returns the raw request.
+    fn serve_once(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            let response = format!(
+                "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(Year::new(2024).unwrap(), day!(5))
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (base_url, server) = serve_once(200, "1 2 3\n");
+        let client = AocClient::new(&base_url, "abc\n");
+
+        assert_eq!(client.input(puzzle()).unwrap(), "1 2 3\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/5/input "));
+        assert!(request.contains("session=abc\r\n"));
+        assert!(
+            request
+                .to_ascii_lowercase()
+                .contains("user-agent: advent_of_code/")
+        );
+    }
+
+    #[test]
+    fn adds_contact_to_user_agent() {
+        assert_eq!(
+            user_agent(Some("me@example.com")),
+            format!("{USER_AGENT} (me@example.com)")
+        );
+        assert_eq!(user_agent(None), USER_AGENT);
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (base_url, server) = serve_once(
+            200,
+            "<main><article><h2>--- Day 5: Test ---</h2><p>Hello.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.puzzle(puzzle()).unwrap(),
+            "## --- Day 5: Test ---\n\nHello.\n"
+        );
+        assert!(server.join().unwrap().starts_with("GET /2024/day/5 "));
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (base_url, server) = serve_once(
+            200,
+            "<main><article><p>That's the right answer!</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.submit(puzzle(), 2, "42").unwrap(),
+            "That's the right answer!\n"
+        );
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2024/day/5/answer "));
+        assert!(request.ends_with("level=2&answer=42"));
+    }
+
+    #[test]
+    fn errors_for_bad_status() {
+        let (base_url, server) = serve_once(404, "Not found");
+        let client = AocClient::new(&base_url, "abc");
+
+        assert!(matches!(
+            client.input(puzzle()),
+            Err(AocClientError::BadStatus(404))
+        ));
+        server.join().unwrap();
+    }
+}
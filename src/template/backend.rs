@@ -0,0 +1,106 @@
+/// Selects how the template talks to advent of code: the built-in client or aoc-cli.
+use std::{env, fmt::Display};
+
+use crate::template::{
+    PuzzleId,
+    aoc_cli::{self, AocCommandError},
+    aoc_client::{self, AocClient, AocClientError},
+    submission::SubmissionOutcome,
+};
+
+pub enum Backend {
+    Native(AocClient),
+    AocCli,
+}
+
+#[derive(Debug)]
+pub enum BackendError {
+    Unavailable,
+    Native(AocClientError),
+    AocCli(AocCommandError),
+}
+
+impl Display for BackendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendError::Unavailable => write!(
+                f,
+                "no session cookie found and aoc-cli is not installed. Set `AOC_SESSION` or create `~/.config/adventofcode.session`, or run \"cargo install aoc-cli\"."
+            ),
+            BackendError::Native(e) => write!(f, "{e}"),
+            BackendError::AocCli(e) => write!(f, "failed to call aoc-cli: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
+
+impl From<AocClientError> for BackendError {
+    fn from(e: AocClientError) -> Self {
+        BackendError::Native(e)
+    }
+}
+
+impl From<AocCommandError> for BackendError {
+    fn from(e: AocCommandError) -> Self {
+        BackendError::AocCli(e)
+    }
+}
+
+impl Backend {
+    /// Uses the built-in client if a session cookie is available, aoc-cli otherwise.
+    /// Set `AOC_BACKEND` to `native` or `aoc-cli` to force one of them.
+    pub fn detect() -> Result<Self, BackendError> {
+        match env::var("AOC_BACKEND").as_deref() {
+            Ok("native") => Ok(Backend::Native(AocClient::from_env()?)),
+            Ok("aoc-cli") => {
+                aoc_cli::check()?;
+                Ok(Backend::AocCli)
+            }
+            _ if aoc_client::find_session().is_some() => {
+                Ok(Backend::Native(AocClient::from_env()?))
+            }
+            _ => match aoc_cli::check() {
+                Ok(()) => Ok(Backend::AocCli),
+                Err(_) => Err(BackendError::Unavailable),
+            },
+        }
+    }
+
+    pub fn download(&self, puzzle: PuzzleId) -> Result<(), BackendError> {
+        match self {
+            Backend::Native(client) => client.download(puzzle)?,
+            Backend::AocCli => {
+                aoc_cli::download(puzzle)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetches the puzzle description. The built-in client stores it to the puzzle file, aoc-cli prints it.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<(), BackendError> {
+        match self {
+            Backend::Native(client) => client.read(puzzle)?,
+            Backend::AocCli => {
+                aoc_cli::read(puzzle)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn submit(
+        &self,
+        puzzle: PuzzleId,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmissionOutcome, BackendError> {
+        let message = match self {
+            Backend::Native(client) => client.submit(puzzle, part, answer)?,
+            Backend::AocCli => {
+                let output = aoc_cli::submit(puzzle, part, answer)?;
+                String::from_utf8_lossy(&output.stdout).into_owned()
+            }
+        };
+        Ok(SubmissionOutcome::parse(&message))
+    }
+}
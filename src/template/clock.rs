@@ -0,0 +1,189 @@
+/// Abstracts access to the current time so that time-dependent behavior can be tested without waiting.
+use std::{
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "today")]
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+
+#[cfg(feature = "today")]
+use crate::template::{Day, PuzzleId, Year, day::SERVER_UTC_OFFSET};
+
+pub trait Clock {
+    /// Time elapsed since the unix epoch.
+    fn since_epoch(&self) -> Duration;
+    fn sleep(&self, duration: Duration);
+
+    /// Seconds since the unix epoch, e.g. to check whether a puzzle is unlocked.
+    fn unix_now(&self) -> u64 {
+        self.since_epoch().as_secs()
+    }
+
+    #[cfg(feature = "today")]
+    fn now(&self) -> DateTime<Utc> {
+        let since_epoch = self.since_epoch();
+        DateTime::from_timestamp(
+            i64::try_from(since_epoch.as_secs()).unwrap_or(i64::MAX),
+            since_epoch.subsec_nanos(),
+        )
+        .unwrap_or_default()
+    }
+}
+
+/// A [`Clock`] backed by the system time.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn since_epoch(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Returns the next puzzle to unlock after `now` and its unlock time.
+/// Puzzles unlock at midnight in the timezone of the advent of code server.
+#[cfg(feature = "today")]
+pub fn next_unlock(now: DateTime<Utc>) -> Option<(PuzzleId, DateTime<Utc>)> {
+    let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+    let tomorrow = now.with_timezone(&offset).date_naive().succ_opt()?;
+
+    let date = if tomorrow.month() == 12 {
+        let year = Year::new(u16::try_from(tomorrow.year()).ok()?)?;
+        if u32::from(year.day_count()) >= tomorrow.day() {
+            tomorrow
+        } else {
+            NaiveDate::from_ymd_opt(tomorrow.year() + 1, 12, 1)?
+        }
+    } else {
+        NaiveDate::from_ymd_opt(tomorrow.year(), 12, 1)?
+    };
+
+    let year = Year::new(u16::try_from(date.year()).ok()?)?;
+    let day = Day::new(u8::try_from(date.day()).ok()?)?;
+    let unlock = offset
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .single()?
+        .with_timezone(&Utc);
+
+    Some((PuzzleId::new(year, day), unlock))
+}
+
+/// Blocks until `target` is reached, calling `on_tick` with the remaining time about once per second.
+#[cfg(feature = "today")]
+pub fn wait_until(clock: &impl Clock, target: DateTime<Utc>, mut on_tick: impl FnMut(Duration)) {
+    loop {
+        let Ok(remaining) = (target - clock.now()).to_std() else {
+            return;
+        };
+
+        if remaining.is_zero() {
+            return;
+        }
+
+        on_tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+/// Formats a duration as a countdown, e.g. `1d 02:03:04`.
+pub fn format_countdown(duration: Duration) -> String {
+    let secs = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let countdown = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {countdown}")
+    } else {
+        countdown
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(feature = "test_lib", feature = "today"))]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::{Clock, format_countdown, next_unlock, wait_until};
+
+    struct MockClock {
+        now: Cell<DateTime<Utc>>,
+    }
+
+    impl Clock for MockClock {
+        fn since_epoch(&self) -> Duration {
+            Duration::from_millis(self.now.get().timestamp_millis().try_into().unwrap())
+        }
+
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+        }
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn unlocks_next_day_during_event() {
+        // 23:30 EST on the 4th of december.
+        let (puzzle, unlock) = next_unlock(utc(2024, 12, 5, 4, 30)).unwrap();
+        assert_eq!(puzzle.to_string(), "2024/05");
+        assert_eq!(unlock, utc(2024, 12, 5, 5, 0));
+    }
+
+    #[test]
+    fn unlocks_first_day_before_event() {
+        let (puzzle, unlock) = next_unlock(utc(2025, 11, 20, 12, 0)).unwrap();
+        assert_eq!(puzzle.to_string(), "2025/01");
+        assert_eq!(unlock, utc(2025, 12, 1, 5, 0));
+    }
+
+    #[test]
+    fn unlocks_next_event_after_last_day() {
+        // the 2025 event ends on the 12th.
+        let (puzzle, unlock) = next_unlock(utc(2025, 12, 12, 12, 0)).unwrap();
+        assert_eq!(puzzle.to_string(), "2026/01");
+        assert_eq!(unlock, utc(2026, 12, 1, 5, 0));
+    }
+
+    #[test]
+    fn waits_until_target() {
+        let clock = MockClock {
+            now: Cell::new(utc(2024, 12, 5, 4, 59)),
+        };
+        let mut ticks = 0;
+
+        wait_until(&clock, utc(2024, 12, 5, 5, 0), |_| ticks += 1);
+
+        assert_eq!(ticks, 60);
+        assert_eq!(clock.now(), utc(2024, 12, 5, 5, 0));
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(
+            format_countdown(Duration::from_millis(3_599_500)),
+            "01:00:00"
+        );
+        assert_eq!(format_countdown(Duration::from_secs(90_061)), "1d 01:01:01");
+    }
+}
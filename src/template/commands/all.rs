@@ -1,5 +1,5 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{DaySet, commands::CommandError, run_multi::run_multi};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(days: &DaySet, is_release: bool) -> Result<(), CommandError> {
+    run_multi(days, is_release, false).map(|_| ())
 }
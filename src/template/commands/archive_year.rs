@@ -0,0 +1,299 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::template::{DaySet, Year, commands::CommandError, config, readme_benchmarks};
+
+/// Data folders of the single-year layout, e.g. `data/inputs`.
+const LEGACY_FOLDERS: [&str; 4] = ["inputs", "examples", "puzzles", "submissions"];
+
+const README_PATH: &str = "README.md";
+const CONFIG_PATH: &str = "aoc.toml";
+
+/// Marker of the table maintained by the `advent-readme-stars` action.
+const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// A file of the single-year layout and its path in the year-namespaced layout.
+#[derive(Debug, PartialEq, Eq)]
+struct Move {
+    from: PathBuf,
+    to: PathBuf,
+    /// Solution modules are rewritten to read their data files from the archived year.
+    is_module: bool,
+}
+
+/// Moves the solutions, data files and timings of `year` from the single-year layout into the
+/// year-namespaced layout, resets the readme tables and configures `next` as the new year.
+pub fn handle(year: Year, next: Year, dry_run: bool) -> Result<(), CommandError> {
+    check_years(year, Year::configured(), next)?;
+
+    let moves = plan(year);
+
+    if let Some(conflict) = moves.iter().find(|m| m.to.exists()) {
+        return Err(CommandError::Failed(format!(
+            "Cannot archive {year}, \"{}\" already exists.",
+            conflict.to.display()
+        )));
+    }
+
+    if moves.is_empty() {
+        println!("No files of {year} use the single-year layout, nothing to move.");
+    }
+
+    for m in &moves {
+        let (from, to) = (m.from.display(), m.to.display());
+
+        if dry_run {
+            println!("Would move \"{from}\" to \"{to}\"");
+        } else {
+            move_file(m).map_err(|e| CommandError::Io(format!("Failed to move \"{from}\""), e))?;
+            println!("Moved \"{from}\" to \"{to}\"");
+        }
+    }
+
+    if dry_run {
+        println!("Would reset the readme tables and set the configured year to {next}");
+        println!("🎄 Dry run, no files were changed.");
+        return Ok(());
+    }
+
+    for folder in LEGACY_FOLDERS {
+        // only succeeds for folders that are empty now.
+        let _ = fs::remove_dir(format!("{}/{folder}", config::data_dir()));
+    }
+
+    reset_readme()?;
+    println!("Reset the readme tables");
+
+    set_configured_year(next)?;
+    println!("Set the configured year to {next}");
+
+    println!("🎄 Archived {year}, its solutions still run with e.g. `cargo solve {year}/01`.");
+    Ok(())
+}
+
+/// Files without a year in their path belong to the configured year, so only that year can be
+/// archived.
+fn check_years(year: Year, configured: Year, next: Year) -> Result<(), CommandError> {
+    if year != configured {
+        return Err(CommandError::Failed(format!(
+            "Only the configured year {configured} can be archived, its files have no year in their path. Drop `--year {year}` or configure {year} in aoc.toml first."
+        )));
+    }
+
+    if next == year {
+        return Err(CommandError::Failed(format!(
+            "The next year has to differ from the archived year {year}."
+        )));
+    }
+
+    Ok(())
+}
+
+/// Collects the legacy bins of the year's days, the files of the legacy data folders and the
+/// legacy timings file.
+fn plan(year: Year) -> Vec<Move> {
+    let mut moves = vec![];
+
+    for puzzle in DaySet::all(year).puzzles() {
+        let from = PathBuf::from(format!("src/bin/{}.rs", puzzle.day()));
+        if from.exists() {
+            moves.push(Move {
+                from,
+                to: format!("src/bin/{year}-{}.rs", puzzle.day()).into(),
+                is_module: true,
+            });
+        }
+    }
+
+    let data_dir = config::data_dir();
+
+    for folder in LEGACY_FOLDERS {
+        let Ok(entries) = fs::read_dir(format!("{data_dir}/{folder}")) else {
+            continue;
+        };
+
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_file())
+            .collect();
+        files.sort();
+
+        for from in files {
+            let Some(name) = from.file_name() else {
+                continue;
+            };
+            let to = Path::new(&format!("{data_dir}/{year}/{folder}")).join(name);
+            moves.push(Move {
+                from,
+                to,
+                is_module: false,
+            });
+        }
+    }
+
+    let timings = PathBuf::from(format!("{data_dir}/timings.json"));
+    if timings.exists() {
+        moves.push(Move {
+            from: timings,
+            to: format!("{data_dir}/{year}/timings.json").into(),
+            is_module: false,
+        });
+    }
+
+    moves
+}
+
+fn move_file(m: &Move) -> std::io::Result<()> {
+    if let Some(parent) = m.to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    if m.is_module {
+        let source = fs::read_to_string(&m.from)?;
+        fs::write(&m.to, rewrite_module(&source))?;
+        fs::remove_file(&m.from)
+    } else {
+        fs::rename(&m.from, &m.to)
+    }
+}
+
+/// Legacy modules read their examples for a plain `DAY`, which refers to the configured year.
+/// Reading them for the `PUZZLE` keeps the tests working once the configured year changes.
+fn rewrite_module(source: &str) -> String {
+    let re = Regex::new(r"(read_file(?:_part)?\(\s*[^,()]+,\s*)DAY\b").unwrap();
+    re.replace_all(source, "${1}PUZZLE").into_owned()
+}
+
+fn reset_readme() -> Result<(), CommandError> {
+    let readme = fs::read_to_string(README_PATH)
+        .map_err(|e| CommandError::Io("Failed to read the readme".into(), e))?;
+
+    let mut readme = reset_stars(&readme);
+    // a readme without a benchmark table has nothing to reset.
+    let _ = readme_benchmarks::reset(&mut readme);
+
+    fs::write(README_PATH, readme)
+        .map_err(|e| CommandError::Io("Failed to write the readme".into(), e))
+}
+
+/// Removes the table that follows the stars marker, i.e. a `## <year> Results` heading and the
+/// table rows. The marker is kept so the action fills in the table of the next year.
+fn reset_stars(readme: &str) -> String {
+    let Some(start) = readme.find(STARS_MARKER) else {
+        return readme.to_string();
+    };
+
+    let end = start + STARS_MARKER.len();
+    let rest = &readme[end..];
+
+    let table_len: usize = rest
+        .split_inclusive('\n')
+        .take_while(|line| {
+            let line = line.trim();
+            line.is_empty()
+                || line.starts_with('|')
+                || (line.starts_with("## ") && line.ends_with("Results"))
+        })
+        .map(str::len)
+        .sum();
+
+    format!("{}\n\n{}", &readme[..end], &rest[table_len..])
+}
+
+/// Sets the `year` of `aoc.toml`.
+fn set_configured_year(year: Year) -> Result<(), CommandError> {
+    let config = fs::read_to_string(CONFIG_PATH).unwrap_or_default();
+    fs::write(CONFIG_PATH, set_config_year(&config, year))
+        .map_err(|e| CommandError::Io(format!("Failed to write \"{CONFIG_PATH}\""), e))
+}
+
+/// Replaces the top-level `year` key of a config file, or adds it in front of the first table.
+fn set_config_year(config: &str, year: Year) -> String {
+    let mut lines: Vec<String> = config.lines().map(String::from).collect();
+    let top_level = lines
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .unwrap_or(lines.len());
+
+    match lines[..top_level]
+        .iter()
+        .position(|l| l.split('=').next().is_some_and(|key| key.trim() == "year"))
+    {
+        Some(i) => lines[i] = format!("year = {year}"),
+        None => lines.insert(0, format!("year = {year}")),
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{STARS_MARKER, check_years, reset_stars, rewrite_module, set_config_year};
+    use crate::template::Year;
+
+    #[test]
+    fn rewrites_data_files_of_modules_to_puzzle() {
+        let source = r#"advent_of_code::solution!(1);
+
+const DAYS: u8 = 1;
+
+fn test_part_one() {
+    let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+    let result = part_two(&advent_of_code::template::read_file_part("examples", DAY, 2));
+    println!("{DAYS} {DAY}");
+}
+"#;
+        let rewritten = rewrite_module(source);
+        assert!(rewritten.contains(r#"read_file("examples", PUZZLE)"#));
+        assert!(rewritten.contains(r#"read_file_part("examples", PUZZLE, 2)"#));
+        assert!(rewritten.contains(r#"println!("{DAYS} {DAY}");"#));
+    }
+
+    #[test]
+    fn resets_stars_table() {
+        let readme = format!(
+            "# Title\n\n{STARS_MARKER}\n## 2025 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n| Day 1 | ⭐ | ⭐ |\n\n<!--- benchmarking table --->\n"
+        );
+        assert_eq!(
+            reset_stars(&readme),
+            format!("# Title\n\n{STARS_MARKER}\n\n<!--- benchmarking table --->\n")
+        );
+        assert_eq!(reset_stars("# Title\n"), "# Title\n");
+    }
+
+    #[test]
+    fn sets_configured_year() {
+        let year = Year::new(2026).unwrap();
+
+        assert_eq!(
+            set_config_year("# config\nyear = 2025\n\n[bench]\nyear = 1\n", year),
+            "# config\nyear = 2026\n\n[bench]\nyear = 1\n"
+        );
+        assert_eq!(
+            set_config_year("[bench]\nbudget_ms = 500\n", year),
+            "year = 2026\n[bench]\nbudget_ms = 500\n"
+        );
+        assert_eq!(set_config_year("", year), "year = 2026\n");
+    }
+
+    #[test]
+    fn archives_only_the_configured_year() {
+        let year = |y| Year::new(y).unwrap();
+
+        assert!(check_years(year(2025), year(2025), year(2026)).is_ok());
+        assert!(
+            check_years(year(2024), year(2025), year(2025))
+                .unwrap_err()
+                .to_string()
+                .starts_with("Only the configured year 2025 can be archived")
+        );
+        assert!(check_years(year(2025), year(2025), year(2025)).is_err());
+    }
+}
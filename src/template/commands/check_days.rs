@@ -0,0 +1,189 @@
+use std::{fs, path::Path, process::Command};
+
+use crate::template::{ANSI_BOLD, ANSI_RESET, DaySet, PuzzleId, commands::CommandError};
+
+/// The assertion of scaffolded tests that were not filled in yet.
+const PLACEHOLDER: &str = "assert_eq!(result, None);";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TestStatus {
+    Passed,
+    Failed,
+    Ignored,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    ignored: usize,
+    /// Placeholder tests, these pass without checking anything.
+    pending: usize,
+}
+
+/// Runs the tests of every scaffolded day of the selection and prints a table of their results.
+pub fn handle(days: &DaySet) -> Result<(), CommandError> {
+    let puzzles: Vec<PuzzleId> = days
+        .puzzles()
+        .filter(|p| Path::new(&p.bin_path()).exists())
+        .collect();
+
+    if puzzles.is_empty() {
+        return Err(CommandError::Failed(
+            "None of the selected days are scaffolded.".into(),
+        ));
+    }
+
+    println!(
+        "{ANSI_BOLD}{:<9}{:>7}{:>7}{:>8}{:>8}{ANSI_RESET}",
+        "Day", "Passed", "Failed", "Ignored", "Pending"
+    );
+
+    let mut failed_days = 0;
+
+    for puzzle in puzzles {
+        let output = Command::new("cargo")
+            .args(["test", "--bin", &puzzle.bin_name()])
+            .output()
+            .map_err(|e| CommandError::Io("Failed to run cargo".into(), e))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let results = parse_test_results(&stdout);
+
+        // a failing test run without results did not compile.
+        if !output.status.success() && results.is_empty() {
+            failed_days += 1;
+            println!(
+                "{:<9}{:>7}{:>7}{:>8}{:>8}  ❌ does not compile",
+                puzzle.to_string(),
+                "-",
+                "-",
+                "-",
+                "-"
+            );
+            continue;
+        }
+
+        let source = fs::read_to_string(puzzle.bin_path()).unwrap_or_default();
+        let summary = summarize(&results, &pending_tests(&source));
+
+        let status = if summary.failed > 0 {
+            failed_days += 1;
+            "❌"
+        } else if summary.pending > 0 {
+            "⏳"
+        } else {
+            "✅"
+        };
+
+        println!(
+            "{:<9}{:>7}{:>7}{:>8}{:>8}  {status}",
+            puzzle.to_string(),
+            summary.passed,
+            summary.failed,
+            summary.ignored,
+            summary.pending
+        );
+    }
+
+    if failed_days > 0 {
+        return Err(CommandError::Failed(format!(
+            "The tests of {failed_days} day(s) failed. Run `cargo test --bin <day>` for details."
+        )));
+    }
+
+    Ok(())
+}
+
+/// Parses the result lines of the test harness, e.g. `test tests::test_part_one ... ok`.
+fn parse_test_results(output: &str) -> Vec<(String, TestStatus)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, result) = line.strip_prefix("test ")?.split_once(" ... ")?;
+            let status = match result.trim() {
+                "ok" => TestStatus::Passed,
+                "FAILED" => TestStatus::Failed,
+                r if r.starts_with("ignored") => TestStatus::Ignored,
+                _ => return None,
+            };
+            Some((name.to_string(), status))
+        })
+        .collect()
+}
+
+/// Names of the test functions that still contain the scaffolded placeholder assertion.
+fn pending_tests(source: &str) -> Vec<String> {
+    source
+        .split("fn ")
+        .skip(1)
+        .filter(|body| body.contains(PLACEHOLDER))
+        .filter_map(|body| body.split('(').next())
+        .map(|name| name.trim().to_string())
+        .collect()
+}
+
+fn summarize(results: &[(String, TestStatus)], pending: &[String]) -> Summary {
+    let mut summary = Summary::default();
+
+    for (name, status) in results {
+        let function = name.rsplit("::").next().unwrap_or(name);
+
+        match status {
+            TestStatus::Passed if pending.iter().any(|p| p == function) => summary.pending += 1,
+            TestStatus::Passed => summary.passed += 1,
+            TestStatus::Failed => summary.failed += 1,
+            TestStatus::Ignored => summary.ignored += 1,
+        }
+    }
+
+    summary
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Summary, TestStatus, parse_test_results, pending_tests, summarize};
+
+    const OUTPUT: &str = "
+running 3 tests
+test tests::test_part_one ... ok
+test tests::test_part_two ... ok
+test tests::test_parse ... ignored, slow
+
+test result: ok. 2 passed; 0 failed; 1 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn parses_test_results() {
+        assert_eq!(
+            parse_test_results(OUTPUT),
+            vec![
+                ("tests::test_part_one".into(), TestStatus::Passed),
+                ("tests::test_part_two".into(), TestStatus::Passed),
+                ("tests::test_parse".into(), TestStatus::Ignored),
+            ]
+        );
+    }
+
+    #[test]
+    fn treats_placeholder_tests_as_pending() {
+        let source = include_str!("../../template.txt").replacen(
+            "assert_eq!(result, None);",
+            "assert_eq!(result, Some(7));",
+            1,
+        );
+        assert_eq!(pending_tests(&source), vec!["test_part_two"]);
+
+        assert_eq!(
+            summarize(&parse_test_results(OUTPUT), &pending_tests(&source)),
+            Summary {
+                passed: 1,
+                failed: 0,
+                ignored: 1,
+                pending: 1,
+            }
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+use crate::template::usage::{COMMANDS, Command, Flag, Positional, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+impl FromStr for Shell {
+    type Err = ShellFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bash" => Ok(Shell::Bash),
+            "zsh" => Ok(Shell::Zsh),
+            "fish" => Ok(Shell::Fish),
+            _ => Err(ShellFromStrError),
+        }
+    }
+}
+
+/// An error which can be returned when parsing a [`Shell`].
+#[derive(Debug)]
+pub struct ShellFromStrError;
+
+impl Error for ShellFromStrError {}
+
+impl Display for ShellFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a shell: `bash`, `zsh` or `fish`")
+    }
+}
+
+/// Prints a completion script for `cargo <command>` and the `advent_of_code` binary.
+/// Days are completed with the solutions scaffolded in the current directory.
+pub fn handle(shell: Shell) {
+    let script = match shell {
+        Shell::Bash => bash(),
+        Shell::Zsh => zsh(),
+        Shell::Fish => fish(),
+    };
+    print!("{script}");
+}
+
+fn command_names() -> String {
+    COMMANDS
+        .iter()
+        .map(|c| c.name)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn bash() -> String {
+    let mut out = format!(
+        r#"# bash completions for the advent of code template, load them with `source <file>`.
+
+_aoc_commands="{}"
+
+_aoc_days() {{
+    local file
+    for file in src/bin/*.rs; do
+        [[ -e $file ]] || continue
+        file=${{file#src/bin/}}
+        file=${{file%.rs}}
+        echo "${{file/-//}}"
+    done
+}}
+
+_aoc_templates() {{
+    local file
+    for file in templates/*.txt; do
+        [[ -e $file ]] || continue
+        file=${{file#templates/}}
+        echo "${{file%.txt}}"
+    done
+}}
+
+# completes the arguments of the command at index $1 of the words.
+_aoc_complete() {{
+    local index=$1 cur=${{COMP_WORDS[COMP_CWORD]}} prev=${{COMP_WORDS[COMP_CWORD-1]}}
+    COMPREPLY=()
+
+    if (( COMP_CWORD == index )); then
+        COMPREPLY=($(compgen -W "$_aoc_commands" -- "$cur"))
+        return
+    fi
+
+    case "$prev" in
+        --template) COMPREPLY=($(compgen -W "$(_aoc_templates)" -- "$cur")); return ;;
+        --fixture) COMPREPLY=($(compgen -f -- "$cur")); return ;;
+"#,
+        command_names()
+    );
+
+    let other_values: Vec<&str> = COMMANDS
+        .iter()
+        .flat_map(Command::all_flags)
+        .filter(|f| matches!(f.value, Value::Required(v) if v != "template" && v != "path"))
+        .map(|f| f.name)
+        .fold(vec![], |mut names, name| {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+            names
+        });
+    out.push_str(&format!(
+        "        {}) return ;;\n    esac\n\n    local flags values\n    case \"${{COMP_WORDS[index]}}\" in\n",
+        other_values.join("|")
+    ));
+
+    for command in COMMANDS {
+        let flags: Vec<&str> = command.all_flags().map(|f| f.name).collect();
+        let values = match command.positional {
+            Positional::Days { .. } | Positional::Puzzle => "$(_aoc_days)".to_string(),
+            Positional::OneOf(values) => values.join(" "),
+            Positional::Command => "$_aoc_commands".into(),
+            Positional::None | Positional::Other(_) => String::new(),
+        };
+        out.push_str(&format!(
+            "        {}) flags=\"{}\"; values=\"{values}\" ;;\n",
+            command.name,
+            flags.join(" ")
+        ));
+    }
+
+    out.push_str(
+        r#"    esac
+
+    if [[ $cur == -* ]]; then
+        COMPREPLY=($(compgen -W "$flags" -- "$cur"))
+    else
+        COMPREPLY=($(compgen -W "$values" -- "$cur"))
+    fi
+}
+
+_advent_of_code() {
+    _aoc_complete 1
+}
+
+# completes `cargo <command>`, other cargo commands are left to the completions of cargo.
+_aoc_cargo() {
+    if (( COMP_CWORD > 1 )) && [[ " $_aoc_commands " == *" ${COMP_WORDS[1]} "* ]]; then
+        _aoc_complete 1
+    elif declare -F _cargo >/dev/null; then
+        _cargo "$@"
+    fi
+}
+
+declare -F _cargo >/dev/null || { declare -F _completion_loader >/dev/null && _completion_loader cargo; }
+complete -F _advent_of_code advent_of_code
+complete -o default -F _aoc_cargo cargo
+"#,
+    );
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Escapes a description for a single-quoted `_arguments` spec.
+fn zsh_escape(s: &str) -> String {
+    s.replace('\'', "'\\''")
+        .replace('[', "\\[")
+        .replace(']', "\\]")
+        .replace(':', "\\:")
+}
+
+fn zsh_flag(flag: &Flag) -> String {
+    let help = zsh_escape(flag.help);
+    match flag.value {
+        Value::None => format!("'{}[{help}]'", flag.name),
+        Value::Required(value) => {
+            let action = match value {
+                "template" => "_aoc_templates",
+                "path" => "_files",
+                _ => " ",
+            };
+            format!("'{}=[{help}]:{value}:{action}'", flag.name)
+        }
+        Value::Optional(value) => format!("'{}=-[{help}]:{value}: '", flag.name),
+    }
+}
+
+fn zsh() -> String {
+    let mut out = String::from(
+        r"# zsh completions for the advent of code template, load them with `source <file>` after `compinit`.
+
+_aoc_days() {
+    local -a days
+    days=(src/bin/*.rs(N:t:r))
+    compadd -- ${days//-//}
+}
+
+_aoc_templates() {
+    local -a templates
+    templates=(templates/*.txt(N:t:r))
+    compadd -- $templates
+}
+
+# completes the arguments of the command in the first word.
+_aoc_complete() {
+    local -a commands
+    commands=(
+",
+    );
+
+    for command in COMMANDS {
+        out.push_str(&format!(
+            "        '{}:{}'\n",
+            command.name,
+            command.about.replace('\'', "'\\''")
+        ));
+    }
+
+    out.push_str(
+        "    )
+
+    if (( CURRENT == 1 )); then
+        _describe command commands
+        return
+    fi
+
+    case $words[1] in
+",
+    );
+
+    for command in COMMANDS {
+        let mut specs: Vec<String> = command.all_flags().map(zsh_flag).collect();
+        match command.positional {
+            Positional::Days { .. } => specs.push("'*:days:_aoc_days'".into()),
+            Positional::Puzzle => specs.push("':day:_aoc_days'".into()),
+            Positional::OneOf(values) => specs.push(format!("':value:({})'", values.join(" "))),
+            Positional::Command => specs.push(format!("':command:({})'", command_names())),
+            Positional::Other(name) => specs.push(format!("':{name}: '")),
+            Positional::None => {}
+        }
+
+        out.push_str(&format!(
+            "        {})\n            _arguments \\\n                {}\n            ;;\n",
+            command.name,
+            specs.join(" \\\n                ")
+        ));
+    }
+
+    out.push_str(&format!(
+        r#"    esac
+}}
+
+_advent_of_code() {{
+    shift words
+    (( CURRENT-- ))
+    _aoc_complete
+}}
+
+# completes `cargo <command>`, other cargo commands are left to the completions of cargo.
+_aoc_cargo() {{
+    if (( CURRENT > 2 && ${{_aoc_commands[(Ie)$words[2]]}} )); then
+        _advent_of_code
+    elif (( $+functions[_cargo] )); then
+        _cargo "$@"
+    fi
+}}
+
+typeset -ga _aoc_commands=({})
+compdef _advent_of_code advent_of_code
+compdef _aoc_cargo cargo
+"#,
+        command_names()
+    ));
+
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn fish() -> String {
+    let mut out = String::from(
+        r"# fish completions for the advent of code template, load them with `source <file>`.
+
+function __aoc_days
+    for file in src/bin/*.rs
+        string replace -r '\.rs$' '' -- (basename $file) | string replace -- - /
+    end
+end
+
+function __aoc_templates
+    for file in templates/*.txt
+        basename $file .txt
+    end
+end
+
+for cmd in advent_of_code cargo
+",
+    );
+
+    let escape = |s: &str| s.replace('\\', "\\\\").replace('\'', "\\'");
+
+    for command in COMMANDS {
+        let name = command.name;
+        out.push_str(&format!(
+            "    complete -c $cmd -n __fish_use_subcommand -f -a {name} -d '{}'\n",
+            escape(command.about)
+        ));
+
+        let condition = format!("'__fish_seen_subcommand_from {name}'");
+
+        for flag in command.all_flags() {
+            let value = match flag.value {
+                Value::Required("template") => " -x -a '(__aoc_templates)'",
+                Value::Required("path") => " -r -F",
+                Value::Required(_) => " -x",
+                Value::None | Value::Optional(_) => "",
+            };
+            out.push_str(&format!(
+                "    complete -c $cmd -n {condition} -l {}{value} -d '{}'\n",
+                flag.name.trim_start_matches('-'),
+                escape(flag.help)
+            ));
+        }
+
+        let values = match command.positional {
+            Positional::Days { .. } | Positional::Puzzle => Some("(__aoc_days)".to_string()),
+            Positional::OneOf(values) => Some(values.join(" ")),
+            Positional::Command => Some(command_names()),
+            Positional::None | Positional::Other(_) => None,
+        };
+        if let Some(values) = values {
+            out.push_str(&format!(
+                "    complete -c $cmd -n {condition} -f -a '{values}'\n"
+            ));
+        }
+    }
+
+    out.push_str("end\n");
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{bash, fish, zsh};
+
+    #[test]
+    fn completes_commands_and_flags() {
+        let bash = bash();
+        assert!(bash.contains("_aoc_commands=\"scaffold download read"));
+        assert!(bash.contains(
+            "        scaffold) flags=\"--download --template --overwrite --dry-run --year --help\"; values=\"$(_aoc_days)\" ;;\n"
+        ));
+
+        let zsh = zsh();
+        assert!(zsh.contains("'--template=[Creates the modules from templates/<template>.txt.]:template:_aoc_templates'"));
+        assert!(zsh.contains("'--overwrite=-[Replaces existing files\\: module (default), input, example or all.]:files: '"));
+
+        let fish = fish();
+        assert!(fish.contains("complete -c $cmd -n '__fish_seen_subcommand_from solve' -l submit -x -d 'Submits the answer of a part.'"));
+        assert!(fish.contains(
+            "complete -c $cmd -n '__fish_seen_subcommand_from completions' -f -a 'bash zsh fish'"
+        ));
+    }
+}
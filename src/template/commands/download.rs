@@ -1,16 +1,261 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, DaySet, PuzzleId,
+    backend::{Backend, BackendError},
+    clock::{Clock, SystemClock},
+    commands::CommandError,
+    examples,
+};
+use std::{collections::HashSet, fs, time::Duration};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+/// Minimum time between two downloads, to go easy on the advent of code servers.
+const MIN_DOWNLOAD_INTERVAL: Duration = Duration::from_secs(2);
+
+/// What to do for a selected day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Plan {
+    Fetch,
+    /// The input was downloaded before.
+    Skip,
+    /// The puzzle is not unlocked yet, requesting it would only cause an error.
+    Locked,
+}
+
+enum Status {
+    Downloaded,
+    Skipped,
+    Locked,
+    Failed(BackendError),
+}
+
+/// Downloads the inputs and descriptions of the selected days. Days that already have an input and
+/// the description of part two are skipped unless `force` is set, puzzles that are not unlocked yet
+/// are never requested.
+pub fn handle(days: &DaySet, force: bool) -> Result<(), CommandError> {
+    let clock = SystemClock;
+    let existing: HashSet<String> = days
+        .puzzles()
+        .filter(|puzzle| has_content(&puzzle.input_path()) && examples::has_part_two(*puzzle))
+        .map(|puzzle| puzzle.input_path())
+        .collect();
+
+    let mut backend: Option<Backend> = None;
+    let mut last_download: Option<Duration> = None;
+    let mut summary: Vec<(PuzzleId, Status)> = vec![];
+
+    for (puzzle, plan) in plan(days, &clock, &existing, force) {
+        match plan {
+            Plan::Locked => {
+                summary.push((puzzle, Status::Locked));
+                continue;
+            }
+            Plan::Skip => {
+                summary.push((puzzle, Status::Skipped));
+                continue;
+            }
+            Plan::Fetch => {}
+        }
+
+        let backend = match &mut backend {
+            Some(backend) => backend,
+            None => backend.insert(Backend::detect()?),
+        };
+
+        throttle(&clock, last_download);
+
+        let status = match backend.download(puzzle) {
+            Ok(()) => {
+                for path in examples::write_examples(puzzle) {
+                    println!("🎄 Extracted example to \"{path}\".");
+                }
+                examples::write_answers(puzzle, force);
+                Status::Downloaded
+            }
+            Err(e) => Status::Failed(e),
+        };
+
+        last_download = Some(clock.since_epoch());
+        summary.push((puzzle, status));
+    }
+
+    if let [(puzzle, status)] = summary.as_slice() {
+        return match status {
+            Status::Downloaded => Ok(()),
+            Status::Skipped => {
+                println!(
+                    "{puzzle} was already downloaded, including part two. Append `--force` to download it again."
+                );
+                Ok(())
+            }
+            Status::Locked => Err(CommandError::Failed(format!(
+                "Puzzle {puzzle} is not unlocked yet."
+            ))),
+            Status::Failed(e) => Err(CommandError::Failed(format!(
+                "failed to download {puzzle}: {e}"
+            ))),
+        };
+    }
+
+    print_summary(&summary);
+
+    let failed = summary
+        .iter()
+        .filter(|(_, status)| matches!(status, Status::Failed(_)))
+        .count();
+
+    if failed > 0 {
+        return Err(CommandError::Failed(format!(
+            "{failed} of {} downloads failed.",
+            summary.len()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Decides for each selected day whether to download it. `existing` holds the input paths of days
+/// that were completely downloaded before, these are only fetched again with `force`.
+fn plan(
+    days: &DaySet,
+    clock: &impl Clock,
+    existing: &HashSet<String>,
+    force: bool,
+) -> Vec<(PuzzleId, Plan)> {
+    let now = clock.unix_now();
+
+    days.puzzles()
+        .map(|puzzle| {
+            let plan = if !puzzle.is_unlocked(now) {
+                Plan::Locked
+            } else if !force && existing.contains(&puzzle.input_path()) {
+                Plan::Skip
+            } else {
+                Plan::Fetch
+            };
+            (puzzle, plan)
+        })
+        .collect()
+}
+
+/// Waits until the minimum interval since the last download has passed.
+fn throttle(clock: &impl Clock, last_download: Option<Duration>) {
+    if let Some(last) = last_download {
+        let elapsed = clock.since_epoch().saturating_sub(last);
+        let remaining = MIN_DOWNLOAD_INTERVAL.saturating_sub(elapsed);
+        if !remaining.is_zero() {
+            clock.sleep(remaining);
+        }
+    }
+}
+
+/// Scaffolding creates empty input files, these do not count as downloaded.
+fn has_content(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+fn print_summary(summary: &[(PuzzleId, Status)]) {
+    println!();
+    println!("{ANSI_BOLD}Summary{ANSI_RESET}");
+    println!("------");
+
+    for (puzzle, status) in summary {
+        match status {
+            Status::Downloaded => println!("{puzzle}: ✅ downloaded"),
+            Status::Skipped => println!("{puzzle}: ⏭️  skipped, already downloaded"),
+            Status::Locked => println!("{puzzle}: 🔒 not unlocked yet"),
+            Status::Failed(e) => println!("{puzzle}: ❌ {e}"),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{cell::Cell, collections::HashSet, time::Duration};
+
+    use super::{MIN_DOWNLOAD_INTERVAL, Plan, plan, throttle};
+    use crate::template::{DaySet, PuzzleId, Year, clock::Clock};
+
+    struct MockClock {
+        since_epoch: Cell<Duration>,
+        slept: Cell<Duration>,
+    }
+
+    impl MockClock {
+        fn at(puzzle: PuzzleId, offset_secs: i64) -> Self {
+            let secs = puzzle.unlock_timestamp().saturating_add_signed(offset_secs);
+            Self {
+                since_epoch: Cell::new(Duration::from_secs(secs)),
+                slept: Cell::new(Duration::ZERO),
+            }
+        }
+    }
+
+    impl Clock for MockClock {
+        fn since_epoch(&self) -> Duration {
+            self.since_epoch.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.since_epoch.set(self.since_epoch.get() + duration);
+            self.slept.set(self.slept.get() + duration);
+        }
+    }
+
+    fn days() -> DaySet {
+        DaySet::parse_with_year("1,2,3", Some(Year::new(2024).unwrap())).unwrap()
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
-    };
+    fn puzzle(day: &str) -> PuzzleId {
+        PuzzleId::parse_with_year(day, Some(Year::new(2024).unwrap())).unwrap()
+    }
+
+    #[test]
+    fn plans_downloads_of_unlocked_days_without_input() {
+        // one second after day 2 unlocked, day 1 was downloaded before.
+        let clock = MockClock::at(puzzle("2"), 1);
+        let existing = HashSet::from([puzzle("1").input_path()]);
+
+        let plans: Vec<Plan> = plan(&days(), &clock, &existing, false)
+            .into_iter()
+            .map(|(_, plan)| plan)
+            .collect();
+        assert_eq!(plans, vec![Plan::Skip, Plan::Fetch, Plan::Locked]);
+
+        let plans: Vec<Plan> = plan(&days(), &clock, &existing, true)
+            .into_iter()
+            .map(|(_, plan)| plan)
+            .collect();
+        assert_eq!(plans, vec![Plan::Fetch, Plan::Fetch, Plan::Locked]);
+    }
+
+    #[test]
+    fn refuses_puzzles_before_their_unlock() {
+        let clock = MockClock::at(puzzle("1"), -1);
+        let plans = plan(&days(), &clock, &HashSet::new(), true);
+        assert!(plans.iter().all(|(_, plan)| *plan == Plan::Locked));
+    }
+
+    #[test]
+    fn throttles_consecutive_downloads() {
+        let clock = MockClock::at(puzzle("1"), 0);
+
+        throttle(&clock, None);
+        assert_eq!(clock.slept.get(), Duration::ZERO);
+
+        let last = clock.since_epoch();
+        clock.since_epoch.set(last + Duration::from_millis(500));
+        throttle(&clock, Some(last));
+        assert_eq!(
+            clock.slept.get(),
+            MIN_DOWNLOAD_INTERVAL - Duration::from_millis(500)
+        );
+        assert_eq!(clock.since_epoch() - last, MIN_DOWNLOAD_INTERVAL);
+
+        throttle(&clock, Some(last));
+        assert_eq!(
+            clock.slept.get(),
+            MIN_DOWNLOAD_INTERVAL - Duration::from_millis(500)
+        );
+    }
 }
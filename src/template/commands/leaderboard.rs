@@ -0,0 +1,79 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{
+    Day, Year, aoc_client::AocClient, commands::CommandError, config, leaderboard::Leaderboard,
+};
+
+/// Advent of code asks to not request leaderboards more often than every 15 minutes.
+const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Shows a private leaderboard. It is read from `fixture` if provided, from the local cache if
+/// it was fetched recently, and fetched with the built-in client otherwise.
+pub fn handle(
+    year: Year,
+    id: u64,
+    day: Option<Day>,
+    fixture: Option<&str>,
+) -> Result<(), CommandError> {
+    let json = match fixture {
+        Some(path) => fs::read_to_string(path).map_err(|e| {
+            CommandError::Io(format!("Failed to read leaderboard fixture \"{path}\""), e)
+        })?,
+        None => fetch_cached(year, id)?,
+    };
+
+    let leaderboard = Leaderboard::try_from(json)
+        .map_err(|e| CommandError::Failed(format!("Failed to parse leaderboard: {e}")))?;
+
+    print!(
+        "{}",
+        leaderboard.render(year, day.or_else(|| leaderboard.latest_day()))
+    );
+    Ok(())
+}
+
+fn cache_path(year: Year, id: u64) -> String {
+    format!("{}/{year}/leaderboards/{id}.json", config::data_dir())
+}
+
+fn fetch_cached(year: Year, id: u64) -> Result<String, CommandError> {
+    let path = cache_path(year, id);
+
+    let cache_age = fs::metadata(&path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+
+    if cache_age.is_some_and(|age| age < CACHE_TTL)
+        && let Ok(json) = fs::read_to_string(&path)
+    {
+        return Ok(json);
+    }
+
+    let fetched = AocClient::from_env().and_then(|client| client.leaderboard(year, id));
+
+    match fetched {
+        Ok(json) => {
+            if let Some(parent) = Path::new(&path).parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            if let Err(e) = fs::write(&path, &json) {
+                eprintln!("Failed to cache leaderboard: {e}");
+            }
+            Ok(json)
+        }
+        Err(e) => match fs::read_to_string(&path) {
+            Ok(json) => {
+                eprintln!("Failed to fetch leaderboard ({e}), showing the cached version.");
+                Ok(json)
+            }
+            Err(_) => Err(CommandError::Failed(format!(
+                "Failed to fetch leaderboard: {e}"
+            ))),
+        },
+    }
+}
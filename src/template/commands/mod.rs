@@ -1,6 +1,45 @@
+use std::{fmt::Display, io};
+
+use crate::template::backend::BackendError;
+
 pub mod all;
+pub mod archive_year;
+pub mod check_days;
+pub mod completions;
 pub mod download;
+pub mod leaderboard;
+pub mod privacy_check;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod status;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
+pub mod watch;
+
+/// An error that aborts a command. `main` reports it and exits with a non-zero status.
+#[derive(Debug)]
+pub enum CommandError {
+    Backend(BackendError),
+    Io(String, io::Error),
+    Failed(String),
+}
+
+impl Display for CommandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandError::Backend(e) => write!(f, "{e}"),
+            CommandError::Io(context, e) => write!(f, "{context}: {e}"),
+            CommandError::Failed(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<BackendError> for CommandError {
+    fn from(e: BackendError) -> Self {
+        CommandError::Backend(e)
+    }
+}
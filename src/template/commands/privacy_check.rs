@@ -0,0 +1,257 @@
+use std::{
+    env, fs,
+    io::Write,
+    path::{Component, Path, PathBuf},
+    process::{Command, Output},
+};
+
+use crate::template::{commands::CommandError, config};
+
+/// Data folders that hold files advent of code asks not to publish.
+const PRIVATE_FOLDERS: [&str; 2] = ["inputs", "puzzles"];
+
+const GITIGNORE_PATH: &str = ".gitignore";
+
+/// Checks that no inputs or puzzle descriptions are tracked by git and that `.gitignore` keeps
+/// new ones from being added. With `fix`, tracked files are removed from the index (but kept on
+/// disk) and the missing ignore rules are appended to `.gitignore`.
+pub fn handle(fix: bool) -> Result<(), CommandError> {
+    let data_dir = config::data_dir();
+
+    let toplevel = git(&["rev-parse", "--show-toplevel"])?;
+    let cwd = env::current_dir()
+        .and_then(fs::canonicalize)
+        .map_err(|e| CommandError::Io("Failed to read the working directory".into(), e))?;
+
+    if is_outside(data_dir, &cwd, Path::new(toplevel.trim())) {
+        println!(
+            "\"{data_dir}\" is outside of the repository, its inputs and puzzle descriptions cannot be tracked."
+        );
+        return Ok(());
+    }
+
+    let tracked: Vec<String> = git(&["ls-files", "-z"])?
+        .split('\0')
+        .filter(|path| is_private(path, data_dir))
+        .map(String::from)
+        .collect();
+
+    let mut missing_rules = vec![];
+    for (rule, example) in ignore_rules(data_dir) {
+        if !is_ignored(&example)? {
+            missing_rules.push(rule);
+        }
+    }
+
+    if tracked.is_empty() && missing_rules.is_empty() {
+        println!("No inputs or puzzle descriptions are tracked, they are ignored by git.");
+        return Ok(());
+    }
+
+    for path in &tracked {
+        println!("Tracked: \"{path}\"");
+    }
+    for rule in &missing_rules {
+        println!("Not ignored: \"{rule}\"");
+    }
+
+    if !fix {
+        return Err(CommandError::Failed(format!(
+            "{} private file(s) are tracked and {} ignore rule(s) are missing. Run `cargo privacy-check --fix` to fix this.",
+            tracked.len(),
+            missing_rules.len()
+        )));
+    }
+
+    if !tracked.is_empty() {
+        let mut args = vec!["rm", "--cached", "--quiet", "--"];
+        args.extend(tracked.iter().map(String::as_str));
+        git(&args)?;
+        println!(
+            "Untracked {} file(s), they are kept on disk. Commit the removal to unpublish them.",
+            tracked.len()
+        );
+    }
+
+    if !missing_rules.is_empty() {
+        append_rules(&missing_rules, data_dir)
+            .map_err(|e| CommandError::Io(format!("Failed to update \"{GITIGNORE_PATH}\""), e))?;
+        println!(
+            "Added {} rule(s) to \"{GITIGNORE_PATH}\"",
+            missing_rules.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Whether a path relative to the repository root is an input or puzzle description, either of
+/// the single-year layout (`data/inputs/01.txt`) or of a year (`data/2025/puzzles/01.md`).
+/// Hidden placeholders like `.keep` are not private.
+fn is_private(path: &str, data_dir: &str) -> bool {
+    let data_dir = data_dir.trim_start_matches("./");
+    let Some(rest) = path
+        .strip_prefix(data_dir)
+        .and_then(|rest| rest.strip_prefix('/'))
+    else {
+        return false;
+    };
+
+    let parts: Vec<&str> = rest.split('/').collect();
+    let (folder, file) = match parts[..] {
+        [folder, file] => (folder, file),
+        [year, folder, file] if year.len() == 4 && year.bytes().all(|b| b.is_ascii_digit()) => {
+            (folder, file)
+        }
+        _ => return false,
+    };
+
+    PRIVATE_FOLDERS.contains(&folder) && !file.is_empty() && !file.starts_with('.')
+}
+
+/// Whether the data directory, relative to `cwd`, lies outside of the worktree at `toplevel`.
+/// Git neither tracks nor ignores files there.
+fn is_outside(data_dir: &str, cwd: &Path, toplevel: &Path) -> bool {
+    let mut path = PathBuf::new();
+    for component in cwd.join(data_dir).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                path.pop();
+            }
+            component => path.push(component),
+        }
+    }
+    !path.starts_with(toplevel)
+}
+
+/// The ignore rules for the private folders, each with a file it has to ignore.
+fn ignore_rules(data_dir: &str) -> Vec<(String, String)> {
+    let data_dir = data_dir.trim_start_matches("./");
+
+    PRIVATE_FOLDERS
+        .iter()
+        .flat_map(|folder| {
+            [
+                (
+                    format!("/{data_dir}/{folder}/*"),
+                    format!("{data_dir}/{folder}/01.txt"),
+                ),
+                (
+                    format!("/{data_dir}/*/{folder}/*"),
+                    format!("{data_dir}/2015/{folder}/01.txt"),
+                ),
+            ]
+        })
+        .collect()
+}
+
+fn append_rules(rules: &[String], data_dir: &str) -> std::io::Result<()> {
+    let existing = fs::read_to_string(GITIGNORE_PATH).unwrap_or_default();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(GITIGNORE_PATH)?;
+
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        writeln!(file)?;
+    }
+
+    writeln!(
+        file,
+        "\n# advent of code asks not to publish inputs and puzzle descriptions."
+    )?;
+    for rule in rules {
+        writeln!(file, "{rule}")?;
+    }
+    writeln!(file, "!/{}/**/.keep", data_dir.trim_start_matches("./"))
+}
+
+/// Whether git ignores a path, regardless of whether it is tracked.
+fn is_ignored(path: &str) -> Result<bool, CommandError> {
+    let output = run_git(&["check-ignore", "--no-index", "--quiet", path])?;
+
+    match output.status.code() {
+        Some(0) => Ok(true),
+        Some(1) => Ok(false),
+        _ => Err(git_error(&output)),
+    }
+}
+
+fn git(args: &[&str]) -> Result<String, CommandError> {
+    let output = run_git(args)?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    } else {
+        Err(git_error(&output))
+    }
+}
+
+fn run_git(args: &[&str]) -> Result<Output, CommandError> {
+    Command::new("git")
+        .args(args)
+        .output()
+        .map_err(|e| CommandError::Io("Failed to run git".into(), e))
+}
+
+fn git_error(output: &Output) -> CommandError {
+    CommandError::Failed(format!(
+        "git failed: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+    ))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{ignore_rules, is_outside, is_private};
+
+    #[test]
+    fn detects_private_files() {
+        assert!(is_private("data/inputs/01.txt", "data"));
+        assert!(is_private("data/puzzles/01.md", "data"));
+        assert!(is_private("data/2024/inputs/05.txt", "data"));
+        assert!(is_private("aoc/2024/puzzles/05.md", "./aoc"));
+
+        assert!(!is_private("data/inputs/.keep", "data"));
+        assert!(!is_private("data/examples/01.txt", "data"));
+        assert!(!is_private("data/2024/examples/05.txt", "data"));
+        assert!(!is_private("data/timings.json", "data"));
+        assert!(!is_private("data/2024/leaderboards/1.json", "data"));
+        assert!(!is_private("src/inputs/01.txt", "data"));
+        assert!(!is_private("database/inputs/01.txt", "data"));
+    }
+
+    #[test]
+    fn builds_ignore_rules_for_both_layouts() {
+        let rules: Vec<String> = ignore_rules("./data").into_iter().map(|r| r.0).collect();
+        assert_eq!(
+            rules,
+            vec![
+                "/data/inputs/*",
+                "/data/*/inputs/*",
+                "/data/puzzles/*",
+                "/data/*/puzzles/*"
+            ]
+        );
+    }
+
+    #[test]
+    fn detects_data_dirs_outside_of_repository() {
+        let repo = Path::new("/home/me/aoc");
+
+        assert!(!is_outside("data", repo, repo));
+        assert!(!is_outside("./data", repo, repo));
+        assert!(!is_outside("../aoc/data", repo, repo));
+        assert!(!is_outside("../data", &repo.join("src"), repo));
+        assert!(!is_outside("/home/me/aoc/data", repo, repo));
+
+        assert!(is_outside("../aoc-data", repo, repo));
+        assert!(is_outside("../../aoc-data", &repo.join("src"), repo));
+        assert!(is_outside("/srv/aoc-data", repo, repo));
+    }
+}
@@ -1,17 +1,59 @@
-use std::process;
+use std::{
+    env, fs,
+    io::Write,
+    path::Path,
+    process::{Command, Stdio},
+};
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{PuzzleId, backend::Backend, commands::CommandError, markdown};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+/// Prints the puzzle description. Descriptions are rendered from the downloaded puzzle file,
+/// which is only fetched if it is missing. With `page`, the output is shown in `$PAGER` (`less -R` by default).
+pub fn handle(puzzle: PuzzleId, page: bool) -> Result<(), CommandError> {
+    let puzzle_path = puzzle.puzzle_path();
+
+    if !Path::new(&puzzle_path).exists() {
+        let backend = Backend::detect()?;
+        backend.read(puzzle)?;
+
+        // aoc-cli prints the description itself.
+        if matches!(backend, Backend::AocCli) {
+            return Ok(());
+        }
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let description = fs::read_to_string(&puzzle_path).map_err(|e| {
+        CommandError::Io(format!("Failed to read puzzle file \"{puzzle_path}\""), e)
+    })?;
+    let description = markdown::render(&description);
+
+    if !page || !show_in_pager(&description) {
+        print!("{description}");
+    }
+
+    Ok(())
+}
+
+/// Pipes the text into the pager. Returns `false` if the pager could not be started.
+fn show_in_pager(text: &str) -> bool {
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+    let mut parts = pager.split_whitespace();
+
+    let Some(program) = parts.next() else {
+        return false;
+    };
+
+    let Ok(mut child) = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()
+    else {
+        return false;
     };
+
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(text.as_bytes());
+    }
+
+    child.wait().is_ok()
 }
@@ -1,79 +1,373 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
+    env,
+    error::Error,
+    fmt::Display,
+    fs, iter,
+    path::{Path, PathBuf},
+    str::FromStr,
 };
 
-use crate::template::Day;
+use crate::template::{DaySet, PuzzleId, commands::CommandError, config::ScaffoldConfig, examples};
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
-    }
-    file.truncate(true).write(true).open(path)
+/// Directory of the templates selectable with `--template`, e.g. `templates/grid.txt`.
+const TEMPLATES_DIR: &str = "templates";
+
+/// Name of the template used when neither `--template` nor `aoc.toml` select one, e.g.
+/// `~/.config/advent-of-code/templates/default.txt`. Without it, `src/template.txt` is used.
+const DEFAULT_TEMPLATE: &str = "default";
+
+/// The files of a day that scaffolding replaces if they already exist, parsed from `--overwrite=module,example`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Overwrite {
+    pub module: bool,
+    pub input: bool,
+    pub example: bool,
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+impl Overwrite {
+    /// Overwrites only the module, which is what a bare `--overwrite` selects.
+    pub const MODULE: Self = Self {
+        module: true,
+        input: false,
+        example: false,
+    };
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+impl FromStr for Overwrite {
+    type Err = OverwriteFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut overwrite = Self::default();
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+        for file in s.split(',').map(str::trim) {
+            match file {
+                "module" => overwrite.module = true,
+                "input" => overwrite.input = true,
+                "example" => overwrite.example = true,
+                "all" => {
+                    overwrite = Self {
+                        module: true,
+                        input: true,
+                        example: true,
+                    }
+                }
+                _ => return Err(OverwriteFromStrError),
+            }
         }
+
+        Ok(overwrite)
+    }
+}
+
+/// An error which can be returned when parsing [`Overwrite`].
+#[derive(Debug)]
+pub struct OverwriteFromStrError;
+
+impl Error for OverwriteFromStrError {}
+
+impl Display for OverwriteFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting files to overwrite like `module,example`, choose from `module`, `input`, `example` or `all`")
+    }
+}
+
+/// Attaches the files following a bare `--overwrite` to the flag, so `--overwrite input` reads
+/// like `--overwrite=input`. The next argument is only taken if it parses as files, which keeps
+/// days like in `--overwrite 5` free arguments.
+pub fn attach_overwrite_value(args: &mut Vec<String>) {
+    if let Some(i) = args.iter().position(|arg| arg == "--overwrite")
+        && args
+            .get(i + 1)
+            .is_some_and(|next| next.parse::<Overwrite>().is_ok())
+    {
+        let files = args.remove(i + 1);
+        args[i] = format!("--overwrite={files}");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Create,
+    Overwrite,
+    Skip,
+}
+
+/// Decides what to do with a file of a day. Empty data files are left behind by scaffolding and
+/// count as missing, existing modules and data files with content are only replaced if selected.
+fn plan(path: &str, is_data: bool, overwrite: bool) -> Action {
+    match fs::metadata(path) {
+        Err(_) => Action::Create,
+        Ok(m) if is_data && m.len() == 0 => Action::Create,
+        Ok(_) if overwrite => Action::Overwrite,
+        Ok(_) => Action::Skip,
+    }
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
+}
+
+/// Directory of the templates shared by all repositories of a user. Templates in the repository
+/// take precedence over it.
+fn user_templates_dir() -> Option<PathBuf> {
+    let home = PathBuf::from(env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?);
+    Some(
+        home.join(".config")
+            .join("advent-of-code")
+            .join("templates"),
+    )
+}
+
+/// The directories templates are looked up in, in order of precedence.
+fn template_dirs() -> Vec<PathBuf> {
+    iter::once(PathBuf::from(TEMPLATES_DIR))
+        .chain(user_templates_dir())
+        .collect()
+}
+
+/// Reads the template called `name` from the first of `dirs` that has it. Without a name, a
+/// `default.txt` is looked up instead, falling back to the built-in `src/template.txt`.
+fn read_template(name: Option<&str>, dirs: &[PathBuf]) -> Result<String, CommandError> {
+    let read = |name: &str| {
+        dirs.iter()
+            .find_map(|dir| fs::read_to_string(dir.join(format!("{name}.txt"))).ok())
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    let Some(name) = name else {
+        return Ok(read(DEFAULT_TEMPLATE).unwrap_or_else(|| MODULE_TEMPLATE.to_string()));
+    };
+
+    read(name).ok_or_else(|| {
+        let mut available: Vec<String> = dirs
+            .iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flatten()
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                (path.extension()? == "txt")
+                    .then(|| path.file_stem()?.to_str().map(String::from))?
+            })
+            .collect();
+        available.sort();
+        available.dedup();
+
+        let searched: Vec<String> = dirs
+            .iter()
+            .map(|dir| format!("\"{}/\"", dir.display()))
+            .collect();
+
+        CommandError::Failed(format!(
+            "Template `{name}` does not exist in {}. Available templates: {}.",
+            searched.join(" or "),
+            if available.is_empty() {
+                "none".into()
+            } else {
+                available.join(", ")
+            }
+        ))
+    })
+}
+
+/// Fills the placeholders of a module template:
+/// * `%DAY_NUMBER%`: the day, e.g. `5`.
+/// * `%DAY%`: the zero-padded day, e.g. `05`.
+/// * `%YEAR%`: the year of the event, e.g. `2025`.
+/// * `%TITLE%`: the puzzle title if its description was downloaded, e.g. `Print Queue`, `Day 5` otherwise.
+/// * `%RETURN_TYPE%`: the return type of the parts, e.g. `u32`.
+fn render_template(
+    template: &str,
+    puzzle: PuzzleId,
+    title: Option<&str>,
+    return_type: &str,
+) -> String {
+    let day = puzzle.day().into_inner();
+
+    template
+        .replace("%DAY_NUMBER%", &day.to_string())
+        .replace("%DAY%", &puzzle.day().to_string())
+        .replace("%YEAR%", &puzzle.year().to_string())
+        .replace(
+            "%TITLE%",
+            &title.map_or_else(|| format!("Day {day}"), String::from),
+        )
+        .replace("%RETURN_TYPE%", return_type)
+}
+
+/// Scaffolds the module, input and example file of the selected days from a template, which defaults
+/// to the one configured in `aoc.toml`, then to a `default` template. Existing files are reported
+/// and skipped unless selected in `overwrite`, `dry_run` only reports what would change.
+pub fn handle(
+    days: &DaySet,
+    template: Option<&str>,
+    overwrite: Overwrite,
+    dry_run: bool,
+) -> Result<(), CommandError> {
+    let config = ScaffoldConfig::read_from_file();
+    let template = read_template(template.or(config.template.as_deref()), &template_dirs())?;
+
+    for (i, puzzle) in days.puzzles().enumerate() {
+        if i > 0 {
+            println!();
         }
+        let module = render_template(
+            &template,
+            puzzle,
+            puzzle.title().as_deref(),
+            &config.return_type,
+        );
+        scaffold(puzzle, &module, overwrite, dry_run)?;
     }
+    Ok(())
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create input file: {e}");
-            process::exit(1);
+fn scaffold(
+    puzzle: PuzzleId,
+    module: &str,
+    overwrite: Overwrite,
+    dry_run: bool,
+) -> Result<(), CommandError> {
+    let files = [
+        ("module", puzzle.bin_path(), module, false, overwrite.module),
+        ("input", puzzle.input_path(), "", true, overwrite.input),
+        (
+            "example",
+            puzzle.example_path(),
+            "",
+            true,
+            overwrite.example,
+        ),
+    ];
+
+    for (name, path, contents, is_data, overwrite) in files {
+        let file = if contents.is_empty() {
+            format!("empty {name} file \"{path}\"")
+        } else {
+            format!("{name} file \"{path}\"")
+        };
+
+        match (plan(&path, is_data, overwrite), dry_run) {
+            (Action::Skip, _) => println!(
+                "Skipped existing {name} file \"{path}\". Append `--overwrite={name}` to replace it."
+            ),
+            (Action::Create, true) => println!("Would create {file}"),
+            (Action::Overwrite, true) => println!("Would replace existing {name} file \"{path}\""),
+            (action, false) => {
+                write_file(&path, contents)
+                    .map_err(|e| CommandError::Io(format!("Failed to write {name} file"), e))?;
+
+                match action {
+                    Action::Create => println!("Created {file}"),
+                    _ => println!("Replaced existing {name} file \"{path}\""),
+                }
+            }
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
-        }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
-        }
+    if dry_run {
+        println!("---");
+        println!("🎄 Dry run, no files were changed.");
+        return Ok(());
     }
 
+    examples::write_answers(puzzle, false);
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!("🎄 Type `cargo solve {puzzle}` to run your solution.");
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs};
+
+    use super::{
+        MODULE_TEMPLATE, Overwrite, attach_overwrite_value, read_template, render_template,
+    };
+    use crate::{day, template::PuzzleId, template::Year};
+
+    #[test]
+    fn parses_overwrite_selections() {
+        assert_eq!(
+            "module,example".parse::<Overwrite>().unwrap(),
+            Overwrite {
+                module: true,
+                input: false,
+                example: true,
+            }
+        );
+        assert_eq!(
+            "all".parse::<Overwrite>().unwrap(),
+            Overwrite {
+                module: true,
+                input: true,
+                example: true,
+            }
+        );
+        assert!("inputs".parse::<Overwrite>().is_err());
+    }
+
+    #[test]
+    fn attaches_overwrite_values() {
+        let attach = |s: &str| {
+            let mut args: Vec<String> = s.split_whitespace().map(String::from).collect();
+            attach_overwrite_value(&mut args);
+            args.join(" ")
+        };
+
+        assert_eq!(attach("5 --overwrite input"), "5 --overwrite=input");
+        assert_eq!(attach("5 --overwrite=input"), "5 --overwrite=input");
+        assert_eq!(attach("5 --overwrite"), "5 --overwrite");
+        assert_eq!(attach("--overwrite 5"), "--overwrite 5");
+        assert_eq!(attach("5 --overwrite --dry-run"), "5 --overwrite --dry-run");
+    }
+
+    #[test]
+    fn renders_templates() {
+        let puzzle = PuzzleId::new(Year::new(2024).unwrap(), day!(5));
+        let template = "// %YEAR% day %DAY%: %TITLE%\nadvent_of_code::solution!(%DAY_NUMBER%);\nfn part_one(input: &str) -> Option<%RETURN_TYPE%>";
+
+        assert_eq!(
+            render_template(template, puzzle, Some("Print Queue"), "u64"),
+            "// 2024 day 05: Print Queue\nadvent_of_code::solution!(5);\nfn part_one(input: &str) -> Option<u64>"
+        );
+        assert!(
+            render_template(template, puzzle, None, "u32").starts_with("// 2024 day 05: Day 5\n")
+        );
+        assert!(!render_template(MODULE_TEMPLATE, puzzle, None, "u32").contains('%'));
+    }
+
+    #[test]
+    fn looks_up_templates_in_order() {
+        let root = env::temp_dir().join(format!("aoc-templates-{}", std::process::id()));
+        let (repo, user) = (root.join("repo"), root.join("user"));
+        fs::create_dir_all(&repo).unwrap();
+        fs::create_dir_all(&user).unwrap();
+        fs::write(repo.join("grid.txt"), "repo grid").unwrap();
+        fs::write(user.join("grid.txt"), "user grid").unwrap();
+        fs::write(user.join("lines.txt"), "user lines").unwrap();
+
+        let dirs = [repo.clone(), user.clone()];
+        assert_eq!(read_template(Some("grid"), &dirs).unwrap(), "repo grid");
+        assert_eq!(read_template(Some("lines"), &dirs).unwrap(), "user lines");
+        assert_eq!(read_template(None, &dirs).unwrap(), MODULE_TEMPLATE);
+        let missing = read_template(Some("blocks"), &dirs)
+            .unwrap_err()
+            .to_string();
+        assert!(missing.contains("Available templates: grid, lines."));
+
+        fs::write(user.join("default.txt"), "user default").unwrap();
+        assert_eq!(read_template(None, &dirs).unwrap(), "user default");
+
+        fs::remove_dir_all(root).unwrap();
+    }
 }
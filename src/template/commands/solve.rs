@@ -1,9 +1,50 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::{ANSI_BOLD, ANSI_RESET, DaySet, PuzzleId, commands::CommandError};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Runs the solutions of the selected days. Fails if a solution could not be run or exited with
+/// an error, after running the remaining days.
+pub fn handle(
+    days: &DaySet,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    force: bool,
+) -> Result<(), CommandError> {
+    let mut failed = vec![];
+
+    for (i, puzzle) in days.puzzles().enumerate() {
+        if days.len() > 1 {
+            if i > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day());
+            println!("------");
+        }
+        if !run(puzzle, release, dhat, submit_part, force)? {
+            failed.push(puzzle.to_string());
+        }
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(CommandError::Failed(format!(
+            "The solution of {} failed or its answer was rejected.",
+            failed.join(", ")
+        )))
+    }
+}
+
+/// Runs the solution of a day, returns whether it exited successfully.
+fn run(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    force: bool,
+) -> Result<bool, CommandError> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.bin_name()];
 
     if dhat {
         cmd_args.extend([
@@ -21,14 +62,18 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
+
+        if force {
+            cmd_args.push("--force".to_string());
+        }
     }
 
-    let mut cmd = Command::new("cargo")
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status()
+        .map_err(|e| CommandError::Io("Failed to run cargo".into(), e))?;
 
-    cmd.wait().unwrap();
+    Ok(status.success())
 }
@@ -0,0 +1,253 @@
+use std::{
+    fs,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::{
+    ANSI_BOLD, ANSI_RESET, DaySet, Year,
+    commands::CommandError,
+    submission::{SubmissionLog, SubmissionOutcome},
+    timings::{Timing, Timings},
+    unix_now,
+};
+
+/// Printed by advent of code below each solved part of a puzzle description.
+const SOLVED_MARKER: &str = "Your puzzle answer was";
+
+/// The state of an answer of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Answer {
+    Accepted,
+    /// The part was solved elsewhere, the submitted answers were not checked.
+    AlreadySolved,
+    /// Answers were submitted, but none was accepted.
+    Rejected,
+    Missing,
+}
+
+impl Answer {
+    fn mark(self) -> &'static str {
+        match self {
+            Answer::Accepted => "★",
+            Answer::AlreadySolved => "○",
+            Answer::Rejected => "✗",
+            Answer::Missing => "·",
+        }
+    }
+}
+
+/// Prints an overview of every day of an event: which files exist, which answers were accepted
+/// and whether the stored timings are up to date.
+pub fn handle(year: Year) -> Result<(), CommandError> {
+    println!(
+        "{ANSI_BOLD}{:<9}{:<5}{:<7}{:<9}{:<8}{:<4}{:<4}{:<8}Title{ANSI_RESET}",
+        "Day", "Bin", "Input", "Example", "Puzzle", "P1", "P2", "Timing"
+    );
+
+    let timings = Timings::read_from_file(year);
+    let timings_modified = modified(&Timings::file_path(year));
+    let (mut stars, mut already_solved, mut scaffolded) = (0, 0, 0);
+
+    for puzzle in DaySet::all(year).puzzles() {
+        let is_scaffolded = Path::new(&puzzle.bin_path()).exists();
+        scaffolded += usize::from(is_scaffolded);
+
+        let description = fs::read_to_string(puzzle.puzzle_path()).ok();
+        let log = SubmissionLog::read_from_file(puzzle);
+        let answers = [1, 2].map(|part| answer(&log, description.as_deref(), part));
+        stars += answers.iter().filter(|a| **a == Answer::Accepted).count();
+        already_solved += answers
+            .iter()
+            .filter(|a| **a == Answer::AlreadySolved)
+            .count();
+
+        let timing = match timings.data.iter().find(|t| t.day == puzzle.day()) {
+            None => "·",
+            Some(timing) if is_stale(timing, modified(&puzzle.bin_path()), timings_modified) => {
+                "stale"
+            }
+            Some(_) => "✔",
+        };
+
+        let title = if puzzle.is_unlocked(unix_now()) {
+            puzzle.title().unwrap_or_default()
+        } else {
+            "(locked)".into()
+        };
+
+        println!(
+            "{:<9}{:<5}{:<7}{:<9}{:<8}{:<4}{:<4}{timing:<8}{title}",
+            puzzle.to_string(),
+            check(is_scaffolded),
+            check(has_content(&puzzle.input_path())),
+            check(has_content(&puzzle.example_path())),
+            check(description.is_some()),
+            answers[0].mark(),
+            answers[1].mark(),
+        );
+    }
+
+    println!();
+    println!(
+        "{}",
+        summary(year.day_count(), stars, already_solved, scaffolded)
+    );
+    println!("{LEGEND}");
+    Ok(())
+}
+
+/// Explains the marks of the overview.
+const LEGEND: &str = "✔ present  · missing  ★ accepted  ○ already solved  ✗ rejected  stale: solution changed since it was timed";
+
+/// The line below the overview. Parts that were solved elsewhere are listed apart from the stars,
+/// as their answers were never checked.
+fn summary(day_count: u8, stars: usize, already_solved: usize, scaffolded: usize) -> String {
+    let already_solved = match already_solved {
+        0 => String::new(),
+        1 => ", 1 part already solved elsewhere".into(),
+        n => format!(", {n} parts already solved elsewhere"),
+    };
+    format!(
+        "{stars}/{} stars{already_solved}, {scaffolded}/{day_count} days scaffolded.",
+        usize::from(day_count) * 2
+    )
+}
+
+fn check(value: bool) -> &'static str {
+    if value { "✔" } else { "·" }
+}
+
+fn has_content(path: &str) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+fn modified(path: &str) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Whether a solution changed since it was timed. Timings stored before the time of each day was
+/// recorded fall back to the modification time of the timings file.
+fn is_stale(
+    timing: &Timing,
+    bin_modified: Option<SystemTime>,
+    timings_modified: Option<SystemTime>,
+) -> bool {
+    let timed_at = timing
+        .timed_at
+        .map(|secs| UNIX_EPOCH + Duration::from_secs(secs))
+        .or(timings_modified);
+    bin_modified > timed_at
+}
+
+/// The state of the answer of a part, from the submission log or, for answers submitted
+/// elsewhere, the solved parts of a downloaded puzzle description.
+fn answer(log: &SubmissionLog, description: Option<&str>, part: u8) -> Answer {
+    let submissions: Vec<&SubmissionOutcome> = log
+        .data
+        .iter()
+        .filter(|s| s.part == part)
+        .map(|s| &s.outcome)
+        .collect();
+
+    let is_solved =
+        description.is_some_and(|d| d.matches(SOLVED_MARKER).count() >= usize::from(part));
+
+    if is_solved || submissions.iter().any(|o| o.is_correct()) {
+        Answer::Accepted
+    } else if submissions
+        .iter()
+        .any(|o| matches!(o, SubmissionOutcome::AlreadySolved))
+    {
+        Answer::AlreadySolved
+    } else if submissions.is_empty() {
+        Answer::Missing
+    } else {
+        Answer::Rejected
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::{Answer, LEGEND, answer, is_stale, summary};
+    use crate::{
+        day,
+        template::{
+            submission::{SubmissionLog, SubmissionOutcome},
+            timings::Timing,
+        },
+    };
+
+    #[test]
+    fn determines_answer_states() {
+        let mut log = SubmissionLog::default();
+        log.record(1, "10", SubmissionOutcome::TooLow, 0, 60);
+        log.record(1, "12", SubmissionOutcome::Correct, 100, 60);
+        log.record(2, "5", SubmissionOutcome::Incorrect, 200, 60);
+
+        assert_eq!(answer(&log, None, 1), Answer::Accepted);
+        assert_eq!(answer(&log, None, 2), Answer::Rejected);
+        assert_eq!(answer(&SubmissionLog::default(), None, 1), Answer::Missing);
+
+        // a part solved elsewhere does not tell whether this answer was correct.
+        let mut already_solved = SubmissionLog::default();
+        already_solved.record(1, "11", SubmissionOutcome::TooHigh, 0, 60);
+        already_solved.record(1, "10", SubmissionOutcome::AlreadySolved, 100, 60);
+        assert_eq!(answer(&already_solved, None, 1), Answer::AlreadySolved);
+
+        let description =
+            "Your puzzle answer was `12`.\n\n## --- Part Two ---\n\nYour puzzle answer was `7`.";
+        assert_eq!(answer(&log, Some(description), 2), Answer::Accepted);
+        assert_eq!(
+            answer(
+                &SubmissionLog::default(),
+                Some("Your puzzle answer was `12`."),
+                2
+            ),
+            Answer::Missing
+        );
+    }
+
+    #[test]
+    fn compares_solutions_with_time_of_their_day() {
+        let at = |secs| Some(UNIX_EPOCH + Duration::from_secs(secs));
+        let mut timing = Timing {
+            day: day!(1),
+            part_1: Some("1ms".into()),
+            part_2: None,
+            total_nanos: 1e6,
+            samples: None,
+            memory_peak: None,
+            parse_time: None,
+            instructions: None,
+            timed_at: Some(100),
+        };
+
+        assert!(!is_stale(&timing, at(50), at(500)));
+        assert!(is_stale(&timing, at(200), at(500)));
+        assert!(!is_stale(&timing, None, at(500)));
+
+        timing.timed_at = None;
+        assert!(!is_stale(&timing, at(200), at(500)));
+        assert!(is_stale(&timing, at(600), at(500)));
+    }
+
+    #[test]
+    fn reports_already_solved_parts_apart_from_stars() {
+        assert_eq!(summary(12, 3, 0, 2), "3/24 stars, 2/12 days scaffolded.");
+        assert_eq!(
+            summary(12, 3, 1, 2),
+            "3/24 stars, 1 part already solved elsewhere, 2/12 days scaffolded."
+        );
+        assert_eq!(
+            summary(25, 0, 2, 0),
+            "0/50 stars, 2 parts already solved elsewhere, 0/25 days scaffolded."
+        );
+        assert_eq!(Answer::AlreadySolved.mark(), "○");
+        assert!(LEGEND.contains("○ already solved"));
+    }
+}
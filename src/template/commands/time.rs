@@ -1,40 +1,38 @@
-use std::collections::HashSet;
-
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::{DaySet, Year, commands::CommandError, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+pub fn handle(
+    year: Year,
+    days: Option<DaySet>,
+    run_all: bool,
+    store: bool,
+) -> Result<(), CommandError> {
+    let stored_timings = Timings::read_from_file(year);
 
-    let days_to_run = day.map_or_else(
-        || {
-            if run_all {
-                all_days().collect()
-            } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
-                    .collect()
-            }
-        },
-        |day| HashSet::from([day]),
-    );
+    let days_to_run = days.unwrap_or_else(|| {
+        if run_all {
+            DaySet::all(year)
+        } else {
+            // when the `--all` flag is not set, filter out days that are fully benched.
+            DaySet::all(year).filter(|day| !stored_timings.is_day_complete(year, day))
+        }
+    });
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&days_to_run, true, true)?.unwrap_or_default();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings
+            .store_file(year)
+            .map_err(|e| CommandError::Io("Failed to store timings".into(), e))?;
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
-            Ok(()) => {
-                println!("Stored updated benchmarks.");
-            }
-            Err(_) => {
-                eprintln!("Failed to store updated benchmarks.");
-            }
-        }
+        readme_benchmarks::update(year, merged_timings).map_err(|e| {
+            CommandError::Failed(format!("Failed to store updated benchmarks: {e}"))
+        })?;
+        println!("Stored updated benchmarks.");
     }
+
+    Ok(())
 }
@@ -0,0 +1,188 @@
+use std::{io::Write, time::Duration};
+
+use crate::template::backend::{Backend, BackendError};
+use crate::template::clock::{Clock, SystemClock, format_countdown, next_unlock, wait_until};
+use crate::template::commands::{
+    CommandError, download, read,
+    scaffold::{self, Overwrite},
+};
+use crate::template::{ANSI_BOLD, ANSI_RESET, DaySet, PuzzleId};
+
+/// Number of download attempts after a puzzle unlocked.
+const DOWNLOAD_ATTEMPTS: u32 = 5;
+
+pub fn handle(wait: bool) -> Result<(), CommandError> {
+    if wait {
+        return handle_wait(&SystemClock);
+    }
+
+    match PuzzleId::today() {
+        Some(puzzle) => {
+            let days = DaySet::from(puzzle);
+            scaffold::handle(&days, None, Overwrite::default(), false)?;
+            download::handle(&days, false)?;
+            read::handle(puzzle, false)
+        }
+        None => Err(CommandError::Failed(
+            "`today` command can only be run during the days of an event \
+            in december. Please use `scaffold` with a specific day \
+            or wait for the next puzzle with `--wait`."
+                .into(),
+        )),
+    }
+}
+
+fn handle_wait(clock: &impl Clock) -> Result<(), CommandError> {
+    let backend = Backend::detect()?;
+    let puzzle = wait_for_unlock(clock)?;
+
+    scaffold::handle(&DaySet::from(puzzle), None, Overwrite::default(), false)?;
+
+    if let Err(e) = download_with_retries(clock, || backend.download(puzzle)) {
+        return Err(CommandError::Failed(format!(
+            "Failed to download puzzle after {DOWNLOAD_ATTEMPTS} attempts: {e}"
+        )));
+    }
+
+    read::handle(puzzle, false)
+}
+
+/// Shows a countdown until the next puzzle unlocks and returns it.
+fn wait_for_unlock(clock: &impl Clock) -> Result<PuzzleId, CommandError> {
+    let Some((puzzle, unlock)) = next_unlock(clock.now()) else {
+        return Err(CommandError::Failed(
+            "Could not determine the next puzzle unlock.".into(),
+        ));
+    };
+
+    let mut stdout = std::io::stdout();
+
+    wait_until(clock, unlock, |remaining| {
+        print!(
+            "\r⏳ Day {} of {} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            puzzle.day(),
+            puzzle.year(),
+            format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+    });
+
+    println!(
+        "\r🎄 Day {} of {} is unlocked!{}",
+        puzzle.day(),
+        puzzle.year(),
+        " ".repeat(16)
+    );
+
+    Ok(puzzle)
+}
+
+/// The puzzle might not be available right at the unlock time, retry with a growing delay.
+fn download_with_retries(
+    clock: &impl Clock,
+    mut download: impl FnMut() -> Result<(), BackendError>,
+) -> Result<(), BackendError> {
+    let mut attempt = 1;
+
+    loop {
+        match download() {
+            Err(e) if attempt < DOWNLOAD_ATTEMPTS => {
+                let delay = Duration::from_secs(2u64.pow(attempt));
+                eprintln!("Download failed ({e}), retrying in {}s...", delay.as_secs());
+                clock.sleep(delay);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{cell::Cell, time::Duration};
+
+    use chrono::{DateTime, TimeZone, Utc};
+
+    use super::{DOWNLOAD_ATTEMPTS, download_with_retries, wait_for_unlock};
+    use crate::template::{backend::BackendError, clock::Clock};
+
+    struct MockClock {
+        now: Cell<DateTime<Utc>>,
+        slept: Cell<Duration>,
+    }
+
+    impl MockClock {
+        fn at(now: DateTime<Utc>) -> Self {
+            Self {
+                now: Cell::new(now),
+                slept: Cell::new(Duration::ZERO),
+            }
+        }
+    }
+
+    impl Clock for MockClock {
+        fn since_epoch(&self) -> Duration {
+            Duration::from_millis(self.now.get().timestamp_millis().try_into().unwrap())
+        }
+
+        fn now(&self) -> DateTime<Utc> {
+            self.now.get()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            self.now.set(self.now.get() + duration);
+            self.slept.set(self.slept.get() + duration);
+        }
+    }
+
+    #[test]
+    fn waits_until_next_puzzle_unlocks() {
+        // 23:58:30 EST on the 4th of december.
+        let clock = MockClock::at(Utc.with_ymd_and_hms(2024, 12, 5, 4, 58, 30).unwrap());
+
+        let puzzle = wait_for_unlock(&clock).unwrap();
+
+        assert_eq!(puzzle.to_string(), "2024/05");
+        assert_eq!(
+            clock.now(),
+            Utc.with_ymd_and_hms(2024, 12, 5, 5, 0, 0).unwrap()
+        );
+        assert_eq!(clock.slept.get(), Duration::from_secs(90));
+    }
+
+    #[test]
+    fn retries_downloads_with_growing_delay() {
+        let clock = MockClock::at(Utc.with_ymd_and_hms(2024, 12, 5, 5, 0, 0).unwrap());
+        let attempts = Cell::new(0);
+
+        let result = download_with_retries(&clock, || {
+            attempts.set(attempts.get() + 1);
+            if attempts.get() < 3 {
+                Err(BackendError::Unavailable)
+            } else {
+                Ok(())
+            }
+        });
+
+        assert!(result.is_ok());
+        assert_eq!(attempts.get(), 3);
+        assert_eq!(clock.slept.get(), Duration::from_secs(2 + 4));
+    }
+
+    #[test]
+    fn gives_up_after_exhausting_retries() {
+        let clock = MockClock::at(Utc.with_ymd_and_hms(2024, 12, 5, 5, 0, 0).unwrap());
+        let attempts = Cell::new(0);
+
+        let result = download_with_retries(&clock, || {
+            attempts.set(attempts.get() + 1);
+            Err(BackendError::Unavailable)
+        });
+
+        assert!(matches!(result, Err(BackendError::Unavailable)));
+        assert_eq!(attempts.get(), DOWNLOAD_ATTEMPTS);
+        assert_eq!(clock.slept.get(), Duration::from_secs(2 + 4 + 8 + 16));
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PuzzleId, commands::CommandError};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Editors often write a file several times on save, wait for the changes to settle before rerunning.
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+type Snapshot = Vec<(String, Option<SystemTime>)>;
+
+/// Reruns the solution (or the tests with `tests`) of a day whenever its module, the library or
+/// one of its data files changes. The answers of the previous run are shown next to the new ones.
+pub fn handle(puzzle: PuzzleId, release: bool, tests: bool) -> Result<(), CommandError> {
+    let mut previous: [Option<String>; 2] = [None, None];
+    let mut snapshot = take_snapshot(puzzle);
+
+    loop {
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "{ANSI_ITALIC}Watching {} for changes, press Ctrl+C to stop.{ANSI_RESET}",
+            puzzle.bin_name()
+        );
+        println!();
+
+        let answers = run(puzzle, release, tests)?;
+
+        if !tests {
+            print_comparison(&previous, &answers);
+            previous = answers;
+        }
+
+        snapshot = wait_for_change(puzzle, snapshot);
+    }
+}
+
+/// The module and data files of a day, including additional example files, and the library.
+fn watched_paths(puzzle: PuzzleId) -> Vec<String> {
+    let mut paths = vec![
+        puzzle.bin_path(),
+        "src/lib.rs".into(),
+        puzzle.input_path(),
+        puzzle.example_path(),
+    ];
+
+    let prefix = format!("{}-", puzzle.day());
+    if let Ok(entries) = fs::read_dir(puzzle.data_dir("examples")) {
+        for entry in entries.flatten() {
+            if entry.file_name().to_string_lossy().starts_with(&prefix) {
+                paths.push(entry.path().to_string_lossy().into());
+            }
+        }
+    }
+
+    paths.sort();
+    paths
+}
+
+fn take_snapshot(puzzle: PuzzleId) -> Snapshot {
+    watched_paths(puzzle)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Blocks until a watched file changed and no further changes happened for the debounce period.
+fn wait_for_change(puzzle: PuzzleId, snapshot: Snapshot) -> Snapshot {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let mut current = take_snapshot(puzzle);
+
+        if current != snapshot {
+            loop {
+                thread::sleep(DEBOUNCE);
+                let settled = take_snapshot(puzzle);
+                if settled == current {
+                    return settled;
+                }
+                current = settled;
+            }
+        }
+    }
+}
+
+/// Runs the solution or tests of a day, forwarding their output. Returns the answers of the parts.
+fn run(puzzle: PuzzleId, release: bool, tests: bool) -> Result<[Option<String>; 2], CommandError> {
+    let mut cmd_args = vec![
+        if tests { "test" } else { "run" }.to_string(),
+        "--bin".to_string(),
+        puzzle.bin_name(),
+    ];
+
+    if release {
+        cmd_args.push("--release".to_string());
+    }
+
+    let mut child = Command::new("cargo")
+        .args(&cmd_args)
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| CommandError::Io("Failed to run cargo".into(), e))?;
+
+    let mut answers = [None, None];
+
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            println!("{line}");
+            if let Some((part, answer)) = parse_answer(&line) {
+                answers[usize::from(part - 1)] = Some(answer);
+            }
+        }
+    }
+
+    child
+        .wait()
+        .map_err(|e| CommandError::Io("Failed to run cargo".into(), e))?;
+
+    Ok(answers)
+}
+
+/// Parses the answer of a part from a line printed by the runner, e.g. `Part 1: 42 (1.2ms)`.
+fn parse_answer(line: &str) -> Option<(u8, String)> {
+    // the runner prints an intermediate result before the timed one.
+    let line = line.rsplit('\r').next()?;
+    let rest = line.strip_prefix("Part ")?;
+    let (part, result) = rest.split_once(": ")?;
+    let part: u8 = part.parse().ok().filter(|p| (1..=2).contains(p))?;
+
+    let answer = match result.split_once(ANSI_BOLD) {
+        Some((_, answer)) => answer.split(ANSI_RESET).next()?.to_string(),
+        None => result.split_whitespace().next()?.to_string(),
+    };
+
+    Some((part, answer))
+}
+
+fn print_comparison(previous: &[Option<String>; 2], answers: &[Option<String>; 2]) {
+    if previous.iter().all(Option::is_none) {
+        return;
+    }
+
+    println!();
+    for (i, (previous, answer)) in previous.iter().zip(answers).enumerate() {
+        let Some(previous) = previous else {
+            continue;
+        };
+
+        let status = if Some(previous) == answer.as_ref() {
+            "unchanged"
+        } else {
+            "changed"
+        };
+        println!(
+            "{ANSI_ITALIC}Part {}: previously {ANSI_RESET}{ANSI_BOLD}{previous}{ANSI_RESET}{ANSI_ITALIC} ({status}){ANSI_RESET}",
+            i + 1
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::parse_answer;
+    use crate::template::{ANSI_BOLD, ANSI_RESET};
+
+    #[test]
+    fn parses_answers_of_runner_output() {
+        let line =
+            format!("Part 1: {ANSI_BOLD}42{ANSI_RESET}\rPart 1: {ANSI_BOLD}42{ANSI_RESET} (1.2ms)");
+        assert_eq!(parse_answer(&line), Some((1, "42".into())));
+        assert_eq!(
+            parse_answer("Part 2: ✖             "),
+            Some((2, "✖".into()))
+        );
+        assert_eq!(parse_answer("Peak memory: 1.5 MiB"), None);
+    }
+}
@@ -0,0 +1,707 @@
+/// Repository configuration, read from an optional `aoc.toml` in the repository root.
+/// Environment variables and command-line flags take precedence over its settings.
+use std::{collections::HashMap, fmt::Display, fs, str::FromStr, sync::OnceLock, time::Duration};
+
+use toml::{Table, Value};
+
+use crate::template::Year;
+
+static CONFIG_FILE_PATH: &str = "./aoc.toml";
+
+/// A column of the benchmark table in the readme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Day,
+    Title,
+    Part1,
+    Part2,
+    Total,
+    Samples,
+    Memory,
+    Lines,
+    ParseTime,
+    Instructions,
+}
+
+impl Column {
+    pub fn header(self) -> &'static str {
+        match self {
+            Column::Day => "Day",
+            Column::Title => "Title",
+            Column::Part1 => "Part 1",
+            Column::Part2 => "Part 2",
+            Column::Total => "Total",
+            Column::Samples => "Samples",
+            Column::Memory => "Peak memory",
+            Column::Lines => "Lines",
+            Column::ParseTime => "Parse",
+            Column::Instructions => "Instructions",
+        }
+    }
+}
+
+impl FromStr for Column {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(Column::Day),
+            "title" => Ok(Column::Title),
+            "part_1" => Ok(Column::Part1),
+            "part_2" => Ok(Column::Part2),
+            "total" => Ok(Column::Total),
+            "samples" => Ok(Column::Samples),
+            "memory" => Ok(Column::Memory),
+            "lines" => Ok(Column::Lines),
+            "parse_time" => Ok(Column::ParseTime),
+            "instructions" => Ok(Column::Instructions),
+            _ => Err(format!("unknown readme column `{s}`.")),
+        }
+    }
+}
+
+/// The key the rows of the benchmark table are sorted by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Day,
+    Title,
+    Total,
+    Lines,
+}
+
+impl FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(SortKey::Day),
+            "title" => Ok(SortKey::Title),
+            "total" => Ok(SortKey::Total),
+            "lines" => Ok(SortKey::Lines),
+            _ => Err(format!("unknown readme sort key `{s}`.")),
+        }
+    }
+}
+
+/// Configures the benchmark table written by `cargo time --store`.
+///
+/// ```toml
+/// [readme]
+/// columns = ["day", "title", "part_1", "part_2", "total", "samples", "memory", "lines", "parse_time", "instructions"]
+/// sort = "total"
+/// descending = true
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReadmeConfig {
+    pub columns: Vec<Column>,
+    pub sort: SortKey,
+    pub descending: bool,
+}
+
+impl Default for ReadmeConfig {
+    fn default() -> Self {
+        Self {
+            columns: vec![Column::Day, Column::Part1, Column::Part2],
+            sort: SortKey::Day,
+            descending: false,
+        }
+    }
+}
+
+impl ReadmeConfig {
+    /// Read the `[readme]` section of the config file. If not present, returns the default table layout.
+    pub fn read_from_file() -> Self {
+        match read_table().and_then(|table| ReadmeConfig::try_from(&table)) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Ignoring readme config: {e}");
+                ReadmeConfig::default()
+            }
+        }
+    }
+}
+
+impl TryFrom<&Table> for ReadmeConfig {
+    type Error = String;
+
+    fn try_from(value: &Table) -> Result<Self, Self::Error> {
+        let mut config = ReadmeConfig::default();
+
+        let Some(readme) = value.get("readme") else {
+            return Ok(config);
+        };
+
+        let readme = readme
+            .as_table()
+            .ok_or("expected `readme` to be a table.")?;
+
+        if let Some(columns) = readme.get("columns") {
+            config.columns = columns
+                .as_array()
+                .ok_or("expected `readme.columns` to be an array.")?
+                .iter()
+                .map(parse_str)
+                .collect::<Result<_, _>>()?;
+
+            if config.columns.is_empty() {
+                return Err("expected `readme.columns` to not be empty.".into());
+            }
+        }
+
+        if let Some(sort) = readme.get("sort") {
+            config.sort = parse_str(sort)?;
+        }
+
+        if let Some(descending) = readme.get("descending") {
+            config.descending = descending
+                .as_bool()
+                .ok_or("expected `readme.descending` to be a boolean.")?;
+        }
+
+        Ok(config)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The event year set by the top-level `year` key. `AOC_YEAR` and `--year` take precedence.
+///
+/// ```toml
+/// year = 2024
+/// ```
+pub fn year() -> Option<Year> {
+    static YEAR: OnceLock<Option<Year>> = OnceLock::new();
+
+    *YEAR.get_or_init(|| match read_table().and_then(|table| parse_year(&table)) {
+        Ok(year) => year,
+        Err(e) => {
+            eprintln!("Ignoring year config: {e}");
+            None
+        }
+    })
+}
+
+fn parse_year(value: &Table) -> Result<Option<Year>, String> {
+    value
+        .get("year")
+        .map(|year| {
+            year.as_integer()
+                .and_then(|x| u16::try_from(x).ok())
+                .and_then(Year::new)
+                .ok_or_else(|| "expected `year` to be the year of an event.".to_string())
+        })
+        .transpose()
+}
+
+/// The directory of inputs, examples and other data files, `data` by default. It is set by the
+/// top-level `data_dir` key, `AOC_DATA_DIR` takes precedence.
+///
+/// ```toml
+/// data_dir = "../aoc-data"
+/// ```
+pub fn data_dir() -> &'static str {
+    static DATA_DIR: OnceLock<String> = OnceLock::new();
+
+    DATA_DIR.get_or_init(|| {
+        if let Ok(dir) = std::env::var("AOC_DATA_DIR")
+            && !dir.is_empty()
+        {
+            return dir;
+        }
+
+        match read_table().and_then(|table| parse_data_dir(&table)) {
+            Ok(dir) => dir,
+            Err(e) => {
+                eprintln!("Ignoring data_dir config: {e}");
+                "data".into()
+            }
+        }
+    })
+}
+
+fn parse_data_dir(value: &Table) -> Result<String, String> {
+    let Some(dir) = value.get("data_dir") else {
+        return Ok("data".into());
+    };
+
+    let dir: String = parse_str(dir)?;
+    let dir = dir.trim_end_matches('/');
+
+    if dir.is_empty() {
+        return Err("expected `data_dir` to not be empty.".into());
+    }
+
+    Ok(dir.to_string())
+}
+
+/// Contact details sent in the user agent of requests to advent of code, e.g. the url of your
+/// repository or an email address. It is set by the top-level `contact` key, `AOC_CONTACT` takes
+/// precedence.
+///
+/// ```toml
+/// contact = "github.com/me/advent-of-code"
+/// ```
+pub fn contact() -> Option<&'static str> {
+    static CONTACT: OnceLock<Option<String>> = OnceLock::new();
+
+    CONTACT
+        .get_or_init(|| {
+            if let Ok(contact) = std::env::var("AOC_CONTACT")
+                && !contact.trim().is_empty()
+            {
+                return Some(contact.trim().to_string());
+            }
+
+            match read_table().and_then(|table| parse_contact(&table)) {
+                Ok(contact) => contact,
+                Err(e) => {
+                    eprintln!("Ignoring contact config: {e}");
+                    None
+                }
+            }
+        })
+        .as_deref()
+}
+
+fn parse_contact(value: &Table) -> Result<Option<String>, String> {
+    let Some(contact) = value.get("contact") else {
+        return Ok(None);
+    };
+
+    let contact: String = parse_str(contact)?;
+    Ok(Some(contact.trim().to_string()).filter(|c| !c.is_empty()))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Configures how long `cargo time` benches each part. Parts are run until the time budget is
+/// used up, but at least `min_samples` and at most `max_samples` times.
+///
+/// ```toml
+/// [bench]
+/// budget_ms = 1000
+/// min_samples = 10
+/// max_samples = 10000
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchConfig {
+    pub budget: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            budget: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10_000,
+        }
+    }
+}
+
+impl BenchConfig {
+    /// Read the `[bench]` section of the config file. If not present, returns the default budget.
+    pub fn read_from_file() -> Self {
+        match read_table().and_then(|table| BenchConfig::try_from(&table)) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Ignoring bench config: {e}");
+                BenchConfig::default()
+            }
+        }
+    }
+}
+
+impl TryFrom<&Table> for BenchConfig {
+    type Error = String;
+
+    fn try_from(value: &Table) -> Result<Self, Self::Error> {
+        let mut config = BenchConfig::default();
+
+        let Some(bench) = value.get("bench") else {
+            return Ok(config);
+        };
+
+        let bench = bench.as_table().ok_or("expected `bench` to be a table.")?;
+
+        let positive = |key: &str| {
+            bench
+                .get(key)
+                .map(|value| {
+                    value
+                        .as_integer()
+                        .and_then(|x| u64::try_from(x).ok())
+                        .filter(|x| *x > 0)
+                        .ok_or_else(|| format!("expected `bench.{key}` to be a positive integer."))
+                })
+                .transpose()
+        };
+
+        if let Some(budget) = positive("budget_ms")? {
+            config.budget = Duration::from_millis(budget);
+        }
+
+        if let Some(min_samples) = positive("min_samples")? {
+            config.min_samples = u128::from(min_samples);
+        }
+
+        if let Some(max_samples) = positive("max_samples")? {
+            config.max_samples = u128::from(max_samples);
+        }
+
+        if config.min_samples > config.max_samples {
+            return Err("expected `bench.min_samples` to not exceed `bench.max_samples`.".into());
+        }
+
+        Ok(config)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Configures the checks before submitting an answer with `cargo solve --submit`.
+/// `--force` skips the checks of a single submission.
+///
+/// ```toml
+/// [submission]
+/// guard = true
+/// cooldown_secs = 60
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionConfig {
+    /// Whether answers are checked against previous submissions before submitting them.
+    pub guard: bool,
+    /// How long to wait after a wrong answer before submitting again.
+    pub cooldown_secs: u64,
+}
+
+impl Default for SubmissionConfig {
+    fn default() -> Self {
+        Self {
+            guard: true,
+            // advent of code blocks answers for at least a minute after a wrong answer.
+            cooldown_secs: 60,
+        }
+    }
+}
+
+impl SubmissionConfig {
+    /// Read the `[submission]` section of the config file. If not present, returns the default checks.
+    pub fn read_from_file() -> Self {
+        match read_table().and_then(|table| SubmissionConfig::try_from(&table)) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Ignoring submission config: {e}");
+                SubmissionConfig::default()
+            }
+        }
+    }
+}
+
+impl TryFrom<&Table> for SubmissionConfig {
+    type Error = String;
+
+    fn try_from(value: &Table) -> Result<Self, Self::Error> {
+        let mut config = SubmissionConfig::default();
+
+        let Some(submission) = value.get("submission") else {
+            return Ok(config);
+        };
+
+        let submission = submission
+            .as_table()
+            .ok_or("expected `submission` to be a table.")?;
+
+        if let Some(guard) = submission.get("guard") {
+            config.guard = guard
+                .as_bool()
+                .ok_or("expected `submission.guard` to be a boolean.")?;
+        }
+
+        if let Some(cooldown) = submission.get("cooldown_secs") {
+            config.cooldown_secs = cooldown
+                .as_integer()
+                .and_then(|x| u64::try_from(x).ok())
+                .ok_or("expected `submission.cooldown_secs` to be a non-negative integer.")?;
+        }
+
+        Ok(config)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Configures the modules created by `cargo scaffold`.
+///
+/// ```toml
+/// [scaffold]
+/// template = "grid"
+/// return_type = "u64"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScaffoldConfig {
+    /// Name of the template in `templates/` (or the user's templates) used when scaffolding
+    /// without `--template`.
+    pub template: Option<String>,
+    pub return_type: String,
+}
+
+impl Default for ScaffoldConfig {
+    fn default() -> Self {
+        Self {
+            template: None,
+            return_type: "u32".into(),
+        }
+    }
+}
+
+impl ScaffoldConfig {
+    /// Read the `[scaffold]` section of the config file. If not present, returns the built-in template settings.
+    pub fn read_from_file() -> Self {
+        match read_table().and_then(|table| ScaffoldConfig::try_from(&table)) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("Ignoring scaffold config: {e}");
+                ScaffoldConfig::default()
+            }
+        }
+    }
+}
+
+impl TryFrom<&Table> for ScaffoldConfig {
+    type Error = String;
+
+    fn try_from(value: &Table) -> Result<Self, Self::Error> {
+        let mut config = ScaffoldConfig::default();
+
+        let Some(scaffold) = value.get("scaffold") else {
+            return Ok(config);
+        };
+
+        let scaffold = scaffold
+            .as_table()
+            .ok_or("expected `scaffold` to be a table.")?;
+
+        if let Some(template) = scaffold.get("template") {
+            config.template = Some(parse_str(template)?);
+        }
+
+        if let Some(return_type) = scaffold.get("return_type") {
+            config.return_type = parse_str(return_type)?;
+        }
+
+        Ok(config)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Overrides for the number of puzzles of an event, read from the `[days]` section.
+///
+/// ```toml
+/// [days]
+/// 2026 = 12
+/// ```
+pub fn day_count_overrides() -> &'static HashMap<u16, u8> {
+    static OVERRIDES: OnceLock<HashMap<u16, u8>> = OnceLock::new();
+
+    OVERRIDES.get_or_init(
+        || match read_table().and_then(|table| parse_day_counts(&table)) {
+            Ok(overrides) => overrides,
+            Err(e) => {
+                eprintln!("Ignoring days config: {e}");
+                HashMap::new()
+            }
+        },
+    )
+}
+
+fn parse_day_counts(value: &Table) -> Result<HashMap<u16, u8>, String> {
+    let Some(days) = value.get("days") else {
+        return Ok(HashMap::new());
+    };
+
+    days.as_table()
+        .ok_or("expected `days` to be a table.")?
+        .iter()
+        .map(|(year, count)| {
+            let year = year
+                .parse()
+                .map_err(|_| format!("expected `days.{year}` to be keyed by a year."))?;
+            let count = count
+                .as_integer()
+                .and_then(|x| u8::try_from(x).ok())
+                .filter(|x| (1..=25).contains(x))
+                .ok_or_else(|| format!("expected `days.{year}` to be between 1 and 25."))?;
+            Ok((year, count))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn read_table() -> Result<Table, String> {
+    match fs::read_to_string(CONFIG_FILE_PATH) {
+        Ok(contents) => contents
+            .parse::<Table>()
+            .map_err(|e| format!("{CONFIG_FILE_PATH} is not a valid TOML file: {e}")),
+        Err(_) => Ok(Table::new()),
+    }
+}
+
+fn parse_str<T: FromStr<Err = E>, E: Display>(value: &Value) -> Result<T, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("expected `{value}` to be a string."))?
+        .parse()
+        .map_err(|e: E| e.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        BenchConfig, Column, ReadmeConfig, ScaffoldConfig, SortKey, SubmissionConfig,
+        parse_contact, parse_data_dir, parse_day_counts, parse_year,
+    };
+    use std::time::Duration;
+    use toml::Table;
+
+    fn parse(s: &str) -> Result<ReadmeConfig, String> {
+        ReadmeConfig::try_from(&s.parse::<Table>().unwrap())
+    }
+
+    #[test]
+    fn defaults_without_readme_section() {
+        assert_eq!(parse("").unwrap(), ReadmeConfig::default());
+    }
+
+    #[test]
+    fn parses_readme_section() {
+        let config = parse(
+            r#"
+            [readme]
+            columns = ["day", "title", "parse_time", "total", "memory", "instructions"]
+            sort = "total"
+            descending = true
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.columns,
+            vec![
+                Column::Day,
+                Column::Title,
+                Column::ParseTime,
+                Column::Total,
+                Column::Memory,
+                Column::Instructions
+            ]
+        );
+        assert_eq!(config.sort, SortKey::Total);
+        assert!(config.descending);
+    }
+
+    #[test]
+    fn errors_for_unknown_columns() {
+        assert!(parse("[readme]\ncolumns = [\"day\", \"foo\"]").is_err());
+    }
+
+    #[test]
+    fn errors_for_empty_columns() {
+        assert!(parse("[readme]\ncolumns = []").is_err());
+    }
+
+    #[test]
+    fn parses_year_and_data_dir() {
+        let table = "year = 2024\ndata_dir = \"../aoc-data/\""
+            .parse::<Table>()
+            .unwrap();
+        assert_eq!(
+            parse_year(&table).unwrap().map(|y| y.into_inner()),
+            Some(2024)
+        );
+        assert_eq!(parse_data_dir(&table).unwrap(), "../aoc-data");
+
+        let table = "".parse::<Table>().unwrap();
+        assert_eq!(parse_year(&table).unwrap(), None);
+        assert_eq!(parse_data_dir(&table).unwrap(), "data");
+        assert_eq!(parse_contact(&table).unwrap(), None);
+
+        let table = "contact = \" me@example.com \"".parse::<Table>().unwrap();
+        assert_eq!(
+            parse_contact(&table).unwrap().as_deref(),
+            Some("me@example.com")
+        );
+
+        let table = "year = 1999".parse::<Table>().unwrap();
+        assert!(parse_year(&table).is_err());
+    }
+
+    #[test]
+    fn parses_bench_section() {
+        let table = "[bench]\nbudget_ms = 500\nmax_samples = 100"
+            .parse::<Table>()
+            .unwrap();
+        assert_eq!(
+            BenchConfig::try_from(&table).unwrap(),
+            BenchConfig {
+                budget: Duration::from_millis(500),
+                min_samples: 10,
+                max_samples: 100,
+            }
+        );
+
+        let table = "[bench]\nmin_samples = 200\nmax_samples = 100"
+            .parse::<Table>()
+            .unwrap();
+        assert!(BenchConfig::try_from(&table).is_err());
+    }
+
+    #[test]
+    fn parses_submission_section() {
+        let table = "[submission]\nguard = false\ncooldown_secs = 120"
+            .parse::<Table>()
+            .unwrap();
+        assert_eq!(
+            SubmissionConfig::try_from(&table).unwrap(),
+            SubmissionConfig {
+                guard: false,
+                cooldown_secs: 120,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_scaffold_section() {
+        let table = "[scaffold]\ntemplate = \"grid\"\nreturn_type = \"u64\""
+            .parse::<Table>()
+            .unwrap();
+        let config = ScaffoldConfig::try_from(&table).unwrap();
+        assert_eq!(config.template.as_deref(), Some("grid"));
+        assert_eq!(config.return_type, "u64");
+
+        let table = "".parse::<Table>().unwrap();
+        assert_eq!(
+            ScaffoldConfig::try_from(&table).unwrap(),
+            ScaffoldConfig::default()
+        );
+    }
+
+    #[test]
+    fn parses_day_counts() {
+        let table = "[days]\n2026 = 12\n2027 = 25".parse::<Table>().unwrap();
+        let overrides = parse_day_counts(&table).unwrap();
+        assert_eq!(overrides.get(&2026), Some(&12));
+        assert_eq!(overrides.get(&2027), Some(&25));
+    }
+
+    #[test]
+    fn errors_for_invalid_day_counts() {
+        let table = "[days]\n2026 = 30".parse::<Table>().unwrap();
+        assert!(parse_day_counts(&table).is_err());
+    }
+}
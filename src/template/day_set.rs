@@ -0,0 +1,241 @@
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{Day, PuzzleId, Year, all_days};
+
+/// A sorted set of days of a single event.
+///
+/// Parsed from a comma-separated selection of days and ranges, e.g. `1-5,8,10-`.
+/// Ranges without an end include all remaining days of the event. The selection can be
+/// prefixed with a year, e.g. `2024/1-5`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet {
+    year: Year,
+    days: BTreeSet<Day>,
+}
+
+impl DaySet {
+    /// Creates an empty [`DaySet`] for a year.
+    pub fn empty(year: Year) -> Self {
+        Self {
+            year,
+            days: BTreeSet::new(),
+        }
+    }
+
+    /// Creates a [`DaySet`] containing every day of a year's event.
+    pub fn all(year: Year) -> Self {
+        Self {
+            year,
+            days: all_days(year).collect(),
+        }
+    }
+
+    pub fn year(&self) -> Year {
+        self.year
+    }
+
+    pub fn len(&self) -> usize {
+        self.days.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+
+    pub fn contains(&self, day: Day) -> bool {
+        self.days.contains(&day)
+    }
+
+    /// Returns the puzzle if the set contains exactly one day, `None` otherwise.
+    pub fn single(&self) -> Option<PuzzleId> {
+        if self.days.len() == 1 {
+            self.puzzles().next()
+        } else {
+            None
+        }
+    }
+
+    /// Iterates the days in ascending order.
+    pub fn days(&self) -> impl Iterator<Item = Day> + '_ {
+        self.days.iter().copied()
+    }
+
+    /// Iterates the puzzles of the days in ascending order.
+    pub fn puzzles(&self) -> impl Iterator<Item = PuzzleId> + '_ {
+        self.days().map(|day| PuzzleId::new(self.year, day))
+    }
+
+    /// Keeps only the days for which the predicate returns `true`.
+    #[must_use]
+    pub fn filter(mut self, predicate: impl Fn(Day) -> bool) -> Self {
+        self.days.retain(|day| predicate(*day));
+        self
+    }
+
+    /// Parses a selection. Selections without a year prefix belong to `year` if provided,
+    /// to the configured year otherwise.
+    pub fn parse_with_year(s: &str, year: Option<Year>) -> Result<Self, DaySetFromStrError> {
+        let (year, selection) = match split_year(s) {
+            Some((y, selection)) => (y.parse().map_err(|_| DaySetFromStrError)?, selection),
+            None => (year.unwrap_or_else(Year::configured), s),
+        };
+
+        let parse_day = |x: &str| -> Result<Day, DaySetFromStrError> {
+            let day: Day = x.trim().parse().map_err(|_| DaySetFromStrError)?;
+            if day.is_in(year) {
+                Ok(day)
+            } else {
+                Err(DaySetFromStrError)
+            }
+        };
+
+        let mut set = Self::empty(year);
+
+        for part in selection.split(',') {
+            let part = part.trim();
+            if part.is_empty() {
+                return Err(DaySetFromStrError);
+            }
+
+            let (start, end) = match part.split_once('-') {
+                Some((start, end)) => (start.trim(), end.trim()),
+                None => (part, part),
+            };
+
+            let start = if start.is_empty() {
+                crate::day!(1)
+            } else {
+                parse_day(start)?
+            };
+
+            let end = if end.is_empty() {
+                all_days(year).last().ok_or(DaySetFromStrError)?
+            } else {
+                parse_day(end)?
+            };
+
+            if start > end {
+                return Err(DaySetFromStrError);
+            }
+
+            set.days
+                .extend(all_days(year).filter(|day| *day >= start && *day <= end));
+        }
+
+        Ok(set)
+    }
+}
+
+/// Splits off a year prefix like `2024/` or `2024-`.
+fn split_year(s: &str) -> Option<(&str, &str)> {
+    let (year, rest) = (s.get(..4)?, s.get(5..)?);
+    let is_separator = matches!(s.as_bytes().get(4), Some(b'/' | b'-'));
+    if is_separator && year.bytes().all(|b| b.is_ascii_digit()) {
+        Some((year, rest))
+    } else {
+        None
+    }
+}
+
+impl From<PuzzleId> for DaySet {
+    fn from(puzzle: PuzzleId) -> Self {
+        Self {
+            year: puzzle.year(),
+            days: BTreeSet::from([puzzle.day()]),
+        }
+    }
+}
+
+impl Display for DaySet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let days: Vec<String> = self
+            .days()
+            .map(|day| day.into_inner().to_string())
+            .collect();
+        write!(f, "{}/{}", self.year, days.join(","))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_year(s, None)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug)]
+pub struct DaySetFromStrError;
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting days of the event like `1-5,8,10-`, optionally prefixed by a year like `2024/`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::DaySet;
+    use crate::{
+        day,
+        template::{Day, Year},
+    };
+
+    fn parse(s: &str) -> Vec<u8> {
+        DaySet::parse_with_year(s, Year::new(2024))
+            .unwrap()
+            .days()
+            .map(Day::into_inner)
+            .collect()
+    }
+
+    #[test]
+    fn parses_single_days() {
+        assert_eq!(parse("5"), vec![5]);
+        assert_eq!(parse("05,3, 1"), vec![1, 3, 5]);
+    }
+
+    #[test]
+    fn parses_ranges() {
+        assert_eq!(parse("1-5,8"), vec![1, 2, 3, 4, 5, 8]);
+        assert_eq!(parse("3-3"), vec![3]);
+        assert_eq!(parse("-3"), vec![1, 2, 3]);
+        assert_eq!(parse("1-2,22-"), vec![1, 2, 22, 23, 24, 25]);
+    }
+
+    #[test]
+    fn open_ranges_end_with_event() {
+        let set = DaySet::parse_with_year("10-", Year::new(2025)).unwrap();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(day!(12)));
+    }
+
+    #[test]
+    fn parses_year_prefix() {
+        let set: DaySet = "2016/1-2".parse().unwrap();
+        assert_eq!(set.year(), Year::new(2016).unwrap());
+        assert_eq!(set.len(), 2);
+
+        let set: DaySet = "2016-05".parse().unwrap();
+        assert_eq!(set.single().unwrap().to_string(), "2016/05");
+    }
+
+    #[test]
+    fn rejects_invalid_selections() {
+        assert!(DaySet::parse_with_year("5-1", Year::new(2024)).is_err());
+        assert!(DaySet::parse_with_year("0", Year::new(2024)).is_err());
+        assert!(DaySet::parse_with_year("1,,2", Year::new(2024)).is_err());
+        assert!(DaySet::parse_with_year("13", Year::new(2025)).is_err());
+        assert!(DaySet::parse_with_year("foo", Year::new(2024)).is_err());
+    }
+}
@@ -0,0 +1,293 @@
+/// Extracts the examples of a puzzle description into example files and their answers into the scaffolded tests.
+use std::{fs, path::Path};
+
+use regex::Regex;
+
+use crate::template::PuzzleId;
+
+/// Returns the distinct code blocks of a puzzle description that follow a paragraph mentioning
+/// "for example", in order of appearance.
+pub fn extract_examples(markdown: &str) -> Vec<String> {
+    let mut examples: Vec<String> = vec![];
+    // the paragraph before a code block introduces it.
+    let mut paragraph = String::new();
+    let mut is_new_paragraph = true;
+    let mut block: Option<Vec<&str>> = None;
+
+    for line in markdown.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        match &mut block {
+            Some(lines) if is_fence => {
+                let example = lines.join("\n") + "\n";
+                if paragraph.to_lowercase().contains("for example")
+                    && !example.trim().is_empty()
+                    && !examples.contains(&example)
+                {
+                    examples.push(example);
+                }
+                block = None;
+                paragraph.clear();
+            }
+            Some(lines) => lines.push(line),
+            None if is_fence => block = Some(vec![]),
+            None if line.trim().is_empty() => is_new_paragraph = true,
+            None => {
+                if is_new_paragraph {
+                    paragraph.clear();
+                    is_new_paragraph = false;
+                }
+                paragraph.push_str(line);
+                paragraph.push(' ');
+            }
+        }
+    }
+
+    examples
+}
+
+/// Writes the examples of a downloaded puzzle to `<day>.txt`, `<day>-2.txt`, ... in the examples folder.
+/// Example files that already have content are never overwritten. Returns the paths of written files.
+pub fn write_examples(puzzle: PuzzleId) -> Vec<String> {
+    let Ok(markdown) = fs::read_to_string(puzzle.puzzle_path()) else {
+        return vec![];
+    };
+
+    let mut written = vec![];
+
+    for (i, example) in extract_examples(&markdown).iter().enumerate() {
+        let path = match i {
+            0 => puzzle.example_path(),
+            #[allow(clippy::cast_possible_truncation)]
+            i => puzzle.data_part_path("examples", i as u8 + 1),
+        };
+
+        if fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+            continue;
+        }
+
+        if let Some(parent) = Path::new(&path).parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+
+        match fs::write(&path, example) {
+            Ok(()) => written.push(path),
+            Err(e) => eprintln!("Failed to write example file \"{path}\": {e}"),
+        }
+    }
+
+    written
+}
+
+/// Heading that starts the description of part two.
+const PART_TWO_MARKER: &str = "--- Part Two ---";
+
+/// Returns the example answers of both parts, i.e. the last highlighted code of each part's description.
+pub fn extract_answers(markdown: &str) -> [Option<String>; 2] {
+    let highlighted = Regex::new(r"`\*([^`*]+)\*`|\*`([^`*]+)`\*").unwrap();
+
+    let (part_one, part_two) = match markdown.find(PART_TWO_MARKER) {
+        Some(i) => (&markdown[..i], Some(&markdown[i..])),
+        None => (markdown, None),
+    };
+
+    let last_answer = |text: &str| {
+        highlighted
+            .captures_iter(text)
+            .last()
+            .and_then(|c| c.get(1).or_else(|| c.get(2)))
+            .map(|m| m.as_str().trim().to_string())
+    };
+
+    [last_answer(part_one), part_two.and_then(last_answer)]
+}
+
+/// Fills the example answer of each part into the assertion of its test. Assertions that were
+/// scaffolded as `None` are filled, e.g. for part two once it unlocked. Assertions that already
+/// hold an answer are only replaced with `force`. Returns the new source and the filled parts.
+pub fn fill_answers(source: &str, answers: &[Option<String>; 2], force: bool) -> (String, Vec<u8>) {
+    const ASSERTION: &str = "assert_eq!(result, ";
+    const PLACEHOLDER: &str = "assert_eq!(result, None);";
+
+    let mut source = source.to_string();
+    let mut filled = vec![];
+
+    for (part, (test, answer)) in ["fn test_part_one()", "fn test_part_two()"]
+        .iter()
+        .zip(answers)
+        .enumerate()
+    {
+        let (Some(answer), Some(start)) = (answer, source.find(test)) else {
+            continue;
+        };
+
+        let body_start = start + test.len();
+        let body_end = source[body_start..]
+            .find("fn ")
+            .map_or(source.len(), |i| body_start + i);
+        let body = &source[body_start..body_end];
+
+        let Some(i) = body.find(ASSERTION) else {
+            continue;
+        };
+        let Some(len) = body[i..].find(");").map(|end| end + 2) else {
+            continue;
+        };
+
+        let current = &body[i..i + len];
+        if current != PLACEHOLDER && !force {
+            continue;
+        }
+
+        let expected = if answer.parse::<i64>().is_ok() {
+            format!("Some({answer})")
+        } else {
+            format!("Some({answer:?}.to_string())")
+        };
+        let assertion = format!("{ASSERTION}{expected});");
+
+        if current == assertion {
+            continue;
+        }
+
+        source.replace_range(body_start + i..body_start + i + len, &assertion);
+        #[allow(clippy::cast_possible_truncation)]
+        filled.push(part as u8 + 1);
+    }
+
+    (source, filled)
+}
+
+/// Whether a downloaded puzzle description includes part two, which unlocks after solving part one.
+pub fn has_part_two(puzzle: PuzzleId) -> bool {
+    fs::read_to_string(puzzle.puzzle_path())
+        .is_ok_and(|markdown| markdown.contains(PART_TWO_MARKER))
+}
+
+/// Fills the example answers of a downloaded puzzle into the tests of its solution module, see
+/// [`fill_answers`].
+pub fn write_answers(puzzle: PuzzleId, force: bool) {
+    let (Ok(markdown), Ok(source)) = (
+        fs::read_to_string(puzzle.puzzle_path()),
+        fs::read_to_string(puzzle.bin_path()),
+    ) else {
+        return;
+    };
+
+    let (source, filled) = fill_answers(&source, &extract_answers(&markdown), force);
+
+    if filled.is_empty() {
+        return;
+    }
+
+    match fs::write(puzzle.bin_path(), source) {
+        Ok(()) => {
+            for part in filled {
+                println!(
+                    "🎄 Filled the example answer of part {part} into \"{}\".",
+                    puzzle.bin_path()
+                );
+            }
+        }
+        Err(e) => eprintln!("Failed to write module file: {e}"),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{extract_answers, extract_examples, fill_answers};
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+Some introduction.
+
+```
+not an example
+```
+
+For example, suppose you have the following list:
+
+```
+1 2
+3 4
+```
+
+In this example, the answer is `*7*`.
+
+Your puzzle answer was `1234`.
+
+## --- Part Two ---
+
+For example, using the same list:
+
+```
+1 2
+3 4
+```
+
+For example, consider this list instead:
+
+```
+5 6
+```
+
+Now, the answer is *`ABC`*.
+";
+
+    #[test]
+    fn extracts_distinct_examples() {
+        assert_eq!(extract_examples(PUZZLE), vec!["1 2\n3 4\n", "5 6\n"]);
+    }
+
+    #[test]
+    fn ignores_puzzles_without_examples() {
+        assert!(extract_examples("## --- Day 1: Test ---\n\nNo code here.\n").is_empty());
+    }
+
+    #[test]
+    fn extracts_answers_per_part() {
+        assert_eq!(
+            extract_answers(PUZZLE),
+            [Some("7".to_string()), Some("ABC".to_string())]
+        );
+        assert_eq!(
+            extract_answers("Part one is `*12*`."),
+            [Some("12".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn fills_scaffolded_assertions() {
+        let source = include_str!("../template.txt");
+        let (source, filled) = fill_answers(source, &[Some("7".into()), Some("ABC".into())], false);
+
+        assert_eq!(filled, vec![1, 2]);
+        assert!(source.contains("assert_eq!(result, Some(7));"));
+        assert!(source.contains("assert_eq!(result, Some(\"ABC\".to_string()));"));
+
+        let (_, filled) = fill_answers(&source, &[Some("8".into()), None], false);
+        assert!(filled.is_empty());
+    }
+
+    #[test]
+    fn fills_part_two_once_it_arrives() {
+        let source = include_str!("../template.txt");
+
+        // part two is not unlocked yet.
+        let (source, filled) = fill_answers(source, &[Some("7".into()), None], false);
+        assert_eq!(filled, vec![1]);
+
+        // the answer of part one was edited in the meantime.
+        let source = source.replace("Some(7)", "Some(8)");
+        let (source, filled) = fill_answers(&source, &[Some("7".into()), Some("9".into())], false);
+        assert_eq!(filled, vec![2]);
+        assert!(source.contains("assert_eq!(result, Some(8));"));
+        assert!(source.contains("assert_eq!(result, Some(9));"));
+
+        let (source, filled) = fill_answers(&source, &[Some("7".into()), Some("9".into())], true);
+        assert_eq!(filled, vec![1]);
+        assert!(source.contains("assert_eq!(result, Some(7));"));
+    }
+}
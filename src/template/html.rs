@@ -0,0 +1,220 @@
+//! Minimal conversion of advent of code puzzle pages to markdown.
+//! Only covers the handful of elements used in puzzle descriptions and answer responses.
+
+/// Extracts the puzzle description articles and the answers that were already given from a puzzle page.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let sections = sections(
+        html,
+        &[
+            ("<article", "</article>"),
+            ("<p>Your puzzle answer was", "</p>"),
+        ],
+    );
+    to_markdown(&sections.concat())
+}
+
+/// Extracts the message of an answer response page.
+pub fn response_to_markdown(html: &str) -> String {
+    let sections = sections(html, &[("<article", "</article>")]);
+    match sections.first() {
+        Some(article) => to_markdown(article),
+        None => to_markdown(html),
+    }
+}
+
+/// Returns the sections of a document that start and end with one of the provided markers, in document order.
+fn sections<'a>(html: &'a str, markers: &[(&str, &str)]) -> Vec<&'a str> {
+    let mut sections = Vec::new();
+    let mut rest = html;
+
+    loop {
+        let next = markers
+            .iter()
+            .filter_map(|(start, end)| rest.find(start).map(|i| (i, *end)))
+            .min_by_key(|(i, _)| *i);
+
+        let Some((start, end_marker)) = next else {
+            return sections;
+        };
+
+        let Some(end) = rest[start..].find(end_marker) else {
+            return sections;
+        };
+
+        let end = start + end + end_marker.len();
+        sections.push(&rest[start..end]);
+        rest = &rest[end..];
+    }
+}
+
+/// Converts an html fragment to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut links: Vec<String> = Vec::new();
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        push_text(&mut out, &rest[..start], in_pre);
+
+        let Some(len) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        let tag = &rest[start + 1..start + len];
+        rest = &rest[start + len + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("pre", false) => {
+                in_pre = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                in_pre = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !in_pre => out.push('`'),
+            ("em", _) if !in_pre => out.push('*'),
+            ("a", false) => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            ("li", false) => out.push_str("* "),
+            ("li", true) => out.push('\n'),
+            ("ul", true) => out.push('\n'),
+            ("br", _) => out.push('\n'),
+            _ => {}
+        }
+    }
+
+    push_text(&mut out, rest, in_pre);
+    tidy(&out)
+}
+
+fn push_text(out: &mut String, text: &str, in_pre: bool) {
+    if in_pre {
+        out.push_str(&decode_entities(text));
+    } else if !(text.trim().is_empty() && text.contains('\n')) {
+        out.push_str(&escape_markdown(&decode_entities(&text.replace('\n', " "))));
+    }
+}
+
+/// Escapes characters that would be read as markdown syntax.
+fn escape_markdown(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '\\' | '*' | '`' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let len = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + len]))
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Strips trailing whitespace and collapses consecutive blank lines.
+fn tidy(s: &str) -> String {
+    let mut out = String::new();
+    let mut blank = true;
+
+    for line in s.lines().map(str::trim_end) {
+        if line.is_empty() {
+            if !blank {
+                out.push('\n');
+            }
+            blank = true;
+        } else {
+            out.push_str(line);
+            out.push('\n');
+            blank = false;
+        }
+    }
+
+    out.trim_end().to_string() + "\n"
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{puzzle_to_markdown, response_to_markdown, to_markdown};
+
+    #[test]
+    fn converts_inline_elements() {
+        assert_eq!(
+            to_markdown(
+                "<p>The answer is <code><em>42</em></code>, see <a href=\"/2024/about\">about</a> &amp; more.</p>"
+            ),
+            "The answer is `*42*`, see [about](/2024/about) & more.\n"
+        );
+    }
+
+    #[test]
+    fn converts_block_elements() {
+        let html = "<h2>--- Day 1: Test ---</h2>\n<p>Example:</p>\n<pre><code>1 &lt; 2\n<em>3</em>\n</code></pre>\n<ul>\n<li>one</li>\n<li>two</li>\n</ul>";
+        assert_eq!(
+            to_markdown(html),
+            "## --- Day 1: Test ---\n\nExample:\n\n```\n1 < 2\n3\n```\n\n* one\n* two\n"
+        );
+    }
+
+    #[test]
+    fn extracts_puzzle_sections() {
+        let html = "<html><main>\
+            <article class=\"day-desc\"><h2>--- Day 1: Test ---</h2><p>Part one.</p></article>\
+            <p>Your puzzle answer was <code>7</code>.</p>\
+            <p>You can also share this puzzle.</p>\
+            </main></html>";
+        assert_eq!(
+            puzzle_to_markdown(html),
+            "## --- Day 1: Test ---\n\nPart one.\n\nYour puzzle answer was `7`.\n"
+        );
+    }
+
+    #[test]
+    fn extracts_response_message() {
+        let html = "<main><article><p>That's the right answer! <a href=\"/2024\">[Return]</a></p></article></main>";
+        assert_eq!(
+            response_to_markdown(html),
+            "That's the right answer! [\\[Return\\]](/2024)\n"
+        );
+    }
+
+    #[test]
+    fn escapes_markdown_in_text() {
+        assert_eq!(to_markdown("<p>2 * 3 = [6]</p>"), "2 \\* 3 = \\[6\\]\n");
+    }
+}
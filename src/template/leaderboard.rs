@@ -0,0 +1,274 @@
+/// Parses and renders private leaderboards.
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year, all_days};
+
+/// A member of a private leaderboard.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Member {
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// Unix timestamps of the stars of each part, keyed by day.
+    pub completions: BTreeMap<Day, [Option<u64>; 2]>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// The most recent day any member got a star on.
+    pub fn latest_day(&self) -> Option<Day> {
+        self.members
+            .iter()
+            .flat_map(|m| m.completions.keys())
+            .max()
+            .copied()
+    }
+
+    /// Renders a table of members ordered by local score with their stars per day. If a day is provided,
+    /// the completion times of its parts, relative to the puzzle unlock, are rendered as well.
+    pub fn render(&self, year: Year, day: Option<Day>) -> String {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by(|a, b| b.local_score.cmp(&a.local_score).then(a.name.cmp(&b.name)));
+
+        let days: String = all_days(year)
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let mut out = format!("{ANSI_BOLD}   # Score {days} Stars Name{ANSI_RESET}\n");
+
+        for (i, member) in members.iter().enumerate() {
+            let stars: Vec<&str> = all_days(year)
+                .map(|d| match member.completions.get(&d) {
+                    Some([Some(_), Some(_)]) => "★★",
+                    Some([Some(_), None]) => "★·",
+                    _ => "··",
+                })
+                .collect();
+
+            out.push_str(&format!(
+                "{:>4} {:>5} {} {:>5} {}\n",
+                format!("{}.", i + 1),
+                member.local_score,
+                stars.join(" "),
+                member.stars,
+                member.name
+            ));
+        }
+
+        if let Some(day) = day {
+            let unlock = PuzzleId::new(year, day).unlock_timestamp();
+
+            out.push_str(&format!(
+                "\n{ANSI_BOLD}{:<6}{:>13} {:>12} Name{ANSI_RESET}\n",
+                format!("Day {day}"),
+                "Part 1",
+                "Part 2"
+            ));
+
+            let mut finishers: Vec<(&Member, [Option<u64>; 2])> = members
+                .iter()
+                .filter_map(|m| m.completions.get(&day).map(|c| (*m, *c)))
+                .collect();
+            finishers.sort_by_key(|(_, [p1, p2])| (p2.is_none(), *p2, *p1));
+
+            for (member, parts) in finishers {
+                let [p1, p2] = parts.map(|ts| {
+                    ts.map_or_else(
+                        || "-".into(),
+                        |ts| format_elapsed(ts.saturating_sub(unlock)),
+                    )
+                });
+                out.push_str(&format!("{p1:>19} {p2:>12} {}\n", member.name));
+            }
+        }
+
+        out
+    }
+}
+
+/// Formats seconds as `hh:mm:ss`, prefixed with the number of days if any.
+fn format_elapsed(secs: u64) -> String {
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let elapsed = format!(
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        secs % 3600 / 60,
+        secs % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {elapsed}")
+    } else {
+        elapsed
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let members = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("members")
+            .ok_or("expected JSON document to have key `members`.")?
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected `json.members` to be an object.")?;
+
+        Ok(Leaderboard {
+            members: members
+                .iter()
+                .map(|(id, member)| Member::try_from((id.as_str(), member)))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<(&str, &JsonValue)> for Member {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from((id, value): (&str, &JsonValue)) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        let name = json
+            .get("name")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .unwrap_or_else(|| format!("(anonymous user #{id})"));
+
+        let local_score = number("local_score")
+            .map(|x| x as u64)
+            .ok_or("Expected member.local_score to be a number.")?;
+
+        let stars = number("stars")
+            .map(|x| x as u64)
+            .ok_or("Expected member.stars to be a number.")?;
+
+        let mut completions = BTreeMap::new();
+
+        if let Some(days) = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+        {
+            for (day, parts) in days {
+                let day: Day = day
+                    .parse()
+                    .map_err(|_| "Expected member.completion_day_level to be keyed by days.")?;
+
+                let timestamp = |part: &str| {
+                    parts
+                        .get::<HashMap<String, JsonValue>>()?
+                        .get(part)?
+                        .get::<HashMap<String, JsonValue>>()?
+                        .get("get_star_ts")?
+                        .get::<f64>()
+                        .map(|x| *x as u64)
+                };
+
+                completions.insert(day, [timestamp("1"), timestamp("2")]);
+            }
+        }
+
+        Ok(Member {
+            name,
+            local_score,
+            stars,
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Leaderboard, format_elapsed};
+    use crate::{day, template::Year};
+
+    // 2025-12-01T05:00:00Z is the unlock of day 1.
+    const FIXTURE: &str = r#"{
+        "owner_id": 1,
+        "event": "2025",
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 10, "global_score": 0, "last_star_ts": 1764652000,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1764565800, "star_index": 1 }, "2": { "get_star_ts": 1764567000, "star_index": 2 } },
+                    "2": { "1": { "get_star_ts": 1764652000, "star_index": 3 } }
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 1, "local_score": 4, "global_score": 0, "last_star_ts": 1764655200,
+                "completion_day_level": {
+                    "1": { "1": { "get_star_ts": 1764655200, "star_index": 4 } }
+                }
+            }
+        }
+    }"#;
+
+    #[test]
+    fn parses_leaderboards() {
+        let leaderboard = Leaderboard::try_from(FIXTURE.to_string()).unwrap();
+        assert_eq!(leaderboard.members.len(), 2);
+
+        let alice = leaderboard
+            .members
+            .iter()
+            .find(|m| m.name == "Alice")
+            .unwrap();
+        assert_eq!(alice.local_score, 10);
+        assert_eq!(
+            alice.completions.get(&day!(1)),
+            Some(&[Some(1_764_565_800), Some(1_764_567_000)])
+        );
+        assert_eq!(
+            alice.completions.get(&day!(2)),
+            Some(&[Some(1_764_652_000), None])
+        );
+
+        assert!(
+            leaderboard
+                .members
+                .iter()
+                .any(|m| m.name == "(anonymous user #2)")
+        );
+        assert_eq!(leaderboard.latest_day(), Some(day!(2)));
+    }
+
+    #[test]
+    fn renders_leaderboards() {
+        let leaderboard = Leaderboard::try_from(FIXTURE.to_string()).unwrap();
+        let table = leaderboard.render(Year::new(2025).unwrap(), Some(day!(1)));
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert!(lines[1].starts_with("  1.    10 ★★ ★· ··"));
+        assert!(lines[1].ends_with("    3 Alice"));
+        assert!(lines[2].starts_with("  2.     4 ★· ··"));
+        assert!(lines[5].ends_with("00:10:00     00:30:00 Alice"));
+        assert!(lines[6].ends_with("1d 01:00:00            - (anonymous user #2)"));
+    }
+
+    #[test]
+    fn formats_elapsed_time() {
+        assert_eq!(format_elapsed(61), "00:01:01");
+        assert_eq!(format_elapsed(90_061), "1d 01:01:01");
+    }
+}
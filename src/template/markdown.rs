@@ -0,0 +1,117 @@
+/// Renders puzzle descriptions for the terminal.
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Renders the markdown of a puzzle description with ANSI styles. Headings and emphasis are bold,
+/// inline code is italic, code blocks are indented and links are reduced to their text.
+pub fn render(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            out.push_str("    ");
+            out.push_str(line);
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            out.push_str(&format!(
+                "{ANSI_BOLD}{}{ANSI_RESET}",
+                unescape(heading.trim())
+            ));
+        } else if let Some(item) = line.strip_prefix("* ").or_else(|| line.strip_prefix("- ")) {
+            out.push_str("  • ");
+            out.push_str(&render_inline(item));
+        } else {
+            out.push_str(&render_inline(line));
+        }
+
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Styles emphasis and inline code and reduces links to their text.
+fn render_inline(line: &str) -> String {
+    let mut out = String::new();
+    let (mut is_bold, mut is_italic) = (false, false);
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    out.push(escaped);
+                }
+            }
+            '*' | '`' => {
+                if c == '*' {
+                    is_bold = !is_bold;
+                } else {
+                    is_italic = !is_italic;
+                }
+                out.push_str(ANSI_RESET);
+                if is_bold {
+                    out.push_str(ANSI_BOLD);
+                }
+                if is_italic {
+                    out.push_str(ANSI_ITALIC);
+                }
+            }
+            ']' if chars.peek() == Some(&'(') => {
+                // skip the link target.
+                for c in chars.by_ref() {
+                    if c == ')' {
+                        break;
+                    }
+                }
+            }
+            '[' => {}
+            c => out.push(c),
+        }
+    }
+
+    if is_bold || is_italic {
+        out.push_str(ANSI_RESET);
+    }
+
+    out
+}
+
+fn unescape(s: &str) -> String {
+    s.replace('\\', "")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::render;
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn renders_headings_and_code_blocks() {
+        assert_eq!(
+            render("## \\-\\-\\- Day 1: Test \\-\\-\\-\n\n```\n1 2\n```\n"),
+            format!("{ANSI_BOLD}--- Day 1: Test ---{ANSI_RESET}\n\n    1 2\n")
+        );
+    }
+
+    #[test]
+    fn renders_inline_styles() {
+        assert_eq!(
+            render("The answer is `*42*`."),
+            format!(
+                "The answer is {ANSI_RESET}{ANSI_ITALIC}{ANSI_RESET}{ANSI_BOLD}{ANSI_ITALIC}42{ANSI_RESET}{ANSI_ITALIC}{ANSI_RESET}.\n"
+            )
+        );
+    }
+
+    #[test]
+    fn renders_links_and_lists() {
+        assert_eq!(render("* see [about](/2024/about)"), "  • see about\n");
+    }
+}
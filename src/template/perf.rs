@@ -0,0 +1,144 @@
+//! Counts the cpu instructions a piece of code retires, using the hardware counters of the
+//! `perf_event_open` interface. Only available on Linux, and only if the kernel allows reading
+//! the counters (see `/proc/sys/kernel/perf_event_paranoid`).
+
+/// Runs `f` and returns its result together with the number of instructions it retired in user
+/// space, or [`None`] if the counter is not available.
+pub fn count_instructions<T>(f: impl FnOnce() -> T) -> (T, Option<u64>) {
+    match counter::Counter::open() {
+        Some(counter) => {
+            counter.start();
+            let result = f();
+            let count = counter.stop();
+            (result, count)
+        }
+        None => (f(), None),
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod counter {
+    use std::{
+        fs::File,
+        io::Read,
+        os::fd::{AsRawFd, FromRawFd},
+    };
+
+    const PERF_TYPE_HARDWARE: u32 = 0;
+    const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+
+    // bits of the flags field: disabled, exclude_kernel and exclude_hv.
+    const FLAGS: u64 = 1 | 1 << 5 | 1 << 6;
+
+    const PERF_FLAG_FD_CLOEXEC: libc::c_ulong = 1 << 3;
+
+    // `_IO('$', n)` request codes.
+    const PERF_EVENT_IOC_ENABLE: libc::Ioctl = 0x2400;
+    const PERF_EVENT_IOC_DISABLE: libc::Ioctl = 0x2401;
+    const PERF_EVENT_IOC_RESET: libc::Ioctl = 0x2403;
+
+    /// The first version (`PERF_ATTR_SIZE_VER0`) of `struct perf_event_attr`. Newer kernels
+    /// accept it and default the fields that were added later.
+    #[repr(C)]
+    struct PerfEventAttr {
+        kind: u32,
+        size: u32,
+        config: u64,
+        sample_period: u64,
+        sample_type: u64,
+        read_format: u64,
+        flags: u64,
+        wakeup_events: u32,
+        bp_type: u32,
+        config1: u64,
+    }
+
+    pub struct Counter(File);
+
+    impl Counter {
+        pub fn open() -> Option<Self> {
+            let attr = PerfEventAttr {
+                kind: PERF_TYPE_HARDWARE,
+                size: u32::try_from(size_of::<PerfEventAttr>()).ok()?,
+                config: PERF_COUNT_HW_INSTRUCTIONS,
+                sample_period: 0,
+                sample_type: 0,
+                read_format: 0,
+                flags: FLAGS,
+                wakeup_events: 0,
+                bp_type: 0,
+                config1: 0,
+            };
+
+            // SAFETY: `attr` is a valid `perf_event_attr` that outlives the call. The counter
+            // measures the calling thread (pid 0) on any cpu (-1), without a group (-1).
+            let fd = unsafe {
+                libc::syscall(
+                    libc::SYS_perf_event_open,
+                    &raw const attr,
+                    0,
+                    -1,
+                    -1,
+                    PERF_FLAG_FD_CLOEXEC,
+                )
+            };
+
+            let fd = i32::try_from(fd).ok().filter(|fd| *fd >= 0)?;
+            // SAFETY: the syscall returned a new file descriptor that nothing else owns.
+            Some(Self(unsafe { File::from_raw_fd(fd) }))
+        }
+
+        pub fn start(&self) {
+            self.ioctl(PERF_EVENT_IOC_RESET);
+            self.ioctl(PERF_EVENT_IOC_ENABLE);
+        }
+
+        pub fn stop(mut self) -> Option<u64> {
+            self.ioctl(PERF_EVENT_IOC_DISABLE);
+
+            let mut count = [0; 8];
+            self.0.read_exact(&mut count).ok()?;
+            Some(u64::from_ne_bytes(count))
+        }
+
+        fn ioctl(&self, request: libc::Ioctl) {
+            // SAFETY: the requests take no argument and only act on the counter's descriptor.
+            unsafe {
+                libc::ioctl(self.0.as_raw_fd(), request, 0);
+            }
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod counter {
+    pub struct Counter;
+
+    impl Counter {
+        pub fn open() -> Option<Self> {
+            None
+        }
+
+        pub fn start(&self) {}
+
+        pub fn stop(self) -> Option<u64> {
+            None
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::count_instructions;
+
+    #[test]
+    fn counts_instructions_if_available() {
+        let (sum, instructions) =
+            count_instructions(|| (0..1000u64).map(std::hint::black_box).sum::<u64>());
+        assert_eq!(sum, 499_500);
+        // the counters are not readable in every environment, e.g. in containers.
+        assert!(instructions.is_none_or(|count| count > 1000));
+    }
+}
@@ -0,0 +1,277 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::template::{Day, Year, config, day::SERVER_UTC_OFFSET, year::days_from_civil};
+
+/// Identifies a single puzzle by its event year and day.
+///
+/// # Display
+/// This value displays as `<year>/<day>`, e.g. `2024/05`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    year: Year,
+    day: Day,
+}
+
+/// Describes where the files of a puzzle live in the repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Files are scoped by year, e.g. `src/bin/2025-01.rs` and `data/2025/inputs/01.txt`.
+    Namespaced,
+    /// Files of a single-year repository, e.g. `src/bin/01.rs` and `data/inputs/01.txt`.
+    /// Only used for puzzles of the configured year that were scaffolded this way.
+    Legacy,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates the [`PuzzleId`] of a solution bin. The year is taken from the bin name (e.g. `2024-05`),
+    /// bins without a year prefix belong to the year of `aoc.toml` the crate was compiled with.
+    pub const fn for_bin(bin_name: &str, day: Day) -> Self {
+        let year = match Year::from_bin_name(bin_name) {
+            Some(year) => year,
+            None => match Year::compiled() {
+                Some(year) => year,
+                None => {
+                    panic!("bins without a year prefix require the `year` of `aoc.toml` to be set.")
+                }
+            },
+        };
+        Self::new(year, day)
+    }
+
+    pub fn year(self) -> Year {
+        self.year
+    }
+
+    pub fn day(self) -> Day {
+        self.day
+    }
+
+    /// Unix timestamp of the puzzle unlock, i.e. midnight server time on its day in december.
+    pub fn unlock_timestamp(self) -> u64 {
+        let days = days_from_civil(
+            i64::from(self.year.into_inner()),
+            12,
+            u32::from(self.day.into_inner()),
+        );
+        let midnight = -i64::from(SERVER_UTC_OFFSET) * 3600;
+        u64::try_from(days * 86_400 + midnight).unwrap_or_default()
+    }
+
+    pub fn is_unlocked(self, now: u64) -> bool {
+        now >= self.unlock_timestamp()
+    }
+
+    /// Returns the layout of this puzzle's files. New puzzles are namespaced by year.
+    pub fn layout(self) -> Layout {
+        if Path::new(&self.bin_path_for(Layout::Namespaced)).exists() {
+            Layout::Namespaced
+        } else if self.year == Year::configured()
+            && Path::new(&self.bin_path_for(Layout::Legacy)).exists()
+        {
+            Layout::Legacy
+        } else {
+            Layout::Namespaced
+        }
+    }
+
+    /// Name of the solution bin, e.g. `2025-01`.
+    pub fn bin_name(self) -> String {
+        self.bin_name_for(self.layout())
+    }
+
+    /// Path of the solution module, e.g. `src/bin/2025-01.rs`.
+    pub fn bin_path(self) -> String {
+        self.bin_path_for(self.layout())
+    }
+
+    /// Directory that holds a kind of data file (e.g. `inputs`) for this puzzle's year.
+    pub fn data_dir(self, folder: &str) -> String {
+        match self.layout() {
+            Layout::Namespaced => format!("{}/{}/{folder}", config::data_dir(), self.year),
+            Layout::Legacy => format!("{}/{folder}", config::data_dir()),
+        }
+    }
+
+    /// Path of a text data file, e.g. `data/2025/inputs/01.txt`.
+    pub fn data_path(self, folder: &str) -> String {
+        format!("{}/{}.txt", self.data_dir(folder), self.day)
+    }
+
+    /// Path of a text data file with a part suffix, e.g. `data/2025/examples/01-2.txt`.
+    pub fn data_part_path(self, folder: &str, part: u8) -> String {
+        format!("{}/{}-{part}.txt", self.data_dir(folder), self.day)
+    }
+
+    pub fn input_path(self) -> String {
+        self.data_path("inputs")
+    }
+
+    pub fn example_path(self) -> String {
+        self.data_path("examples")
+    }
+
+    pub fn puzzle_path(self) -> String {
+        format!("{}/{}.md", self.data_dir("puzzles"), self.day)
+    }
+
+    /// Title of the puzzle, e.g. `Historian Hysteria`. Requires its description to be downloaded.
+    pub fn title(self) -> Option<String> {
+        let description = fs::read_to_string(self.puzzle_path()).ok()?;
+        let marker = format!("Day {}:", self.day.into_inner());
+        let line = description.lines().find(|l| l.contains(&marker))?;
+        let title = line
+            .split_once(&marker)?
+            .1
+            .trim_end_matches(['-', '\\', ' '])
+            .trim();
+        Some(title.to_string())
+    }
+
+    fn bin_name_for(self, layout: Layout) -> String {
+        match layout {
+            Layout::Namespaced => format!("{}-{}", self.year, self.day),
+            Layout::Legacy => self.day.to_string(),
+        }
+    }
+
+    fn bin_path_for(self, layout: Layout) -> String {
+        format!("src/bin/{}.rs", self.bin_name_for(layout))
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if an event is in progress, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        use chrono::Datelike;
+
+        let day = Day::today()?;
+        let year = u16::try_from(crate::template::day::server_now()?.year()).ok()?;
+        Some(Self::new(Year::new(year)?, day))
+    }
+}
+
+impl From<Day> for PuzzleId {
+    /// Creates a [`PuzzleId`] for a day of the configured year.
+    fn from(day: Day) -> Self {
+        Self::new(Year::configured(), day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl PuzzleId {
+    /// Parses a puzzle from either `<year>/<day>`, `<year>-<day>` or `<day>`.
+    /// A plain day belongs to `year` if provided, to the configured year otherwise.
+    pub fn parse_with_year(s: &str, year: Option<Year>) -> Result<Self, PuzzleIdFromStrError> {
+        match s.split_once(['/', '-']) {
+            Some((y, d)) => {
+                let year = y.parse().map_err(|_| PuzzleIdFromStrError)?;
+                let day = d.parse().map_err(|_| PuzzleIdFromStrError)?;
+                Self::checked(year, day)
+            }
+            None => {
+                let day = s.parse().map_err(|_| PuzzleIdFromStrError)?;
+                Self::checked(year.unwrap_or_else(Year::configured), day)
+            }
+        }
+    }
+
+    fn checked(year: Year, day: Day) -> Result<Self, PuzzleIdFromStrError> {
+        if day.is_in(year) {
+            Ok(Self::new(year, day))
+        } else {
+            Err(PuzzleIdFromStrError)
+        }
+    }
+}
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_year(s, None)
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a day of the event, optionally prefixed by a year like `2024/05`")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, template::Year};
+
+    #[test]
+    fn parses_puzzle_ids() {
+        let year = Year::new(2024).unwrap();
+        assert_eq!(
+            "2024/05".parse::<PuzzleId>().unwrap(),
+            PuzzleId::new(year, day!(5))
+        );
+        assert_eq!(
+            "2024-5".parse::<PuzzleId>().unwrap(),
+            PuzzleId::new(year, day!(5))
+        );
+        assert_eq!(
+            PuzzleId::parse_with_year("05", Some(year)).unwrap(),
+            PuzzleId::new(year, day!(5))
+        );
+        assert!("2024/26".parse::<PuzzleId>().is_err());
+        assert!("2025/13".parse::<PuzzleId>().is_err());
+        assert!("1999/01".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn creates_puzzle_ids_for_bins() {
+        let puzzle = PuzzleId::for_bin("2016-12", day!(12));
+        assert_eq!(puzzle.year(), Year::new(2016).unwrap());
+        assert_eq!(puzzle.to_string(), "2016/12");
+    }
+
+    #[test]
+    fn unlocks_at_midnight_est() {
+        let puzzle = PuzzleId::new(Year::new(2025).unwrap(), day!(1));
+        // 2025-12-01T05:00:00Z
+        assert_eq!(puzzle.unlock_timestamp(), 1_764_565_200);
+        assert!(!puzzle.is_unlocked(1_764_565_199));
+        assert!(puzzle.is_unlocked(1_764_565_200));
+    }
+
+    #[test]
+    fn namespaces_paths_by_year() {
+        let puzzle = PuzzleId::new(Year::new(2016).unwrap(), day!(3));
+        assert_eq!(puzzle.bin_path(), "src/bin/2016-03.rs");
+        assert_eq!(puzzle.input_path(), "data/2016/inputs/03.txt");
+        assert_eq!(puzzle.puzzle_path(), "data/2016/puzzles/03.md");
+        assert_eq!(
+            puzzle.data_part_path("examples", 2),
+            "data/2016/examples/03-2.txt"
+        );
+    }
+}
@@ -1,9 +1,10 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{cmp::Ordering, fmt::Display, fs, io, time::Duration};
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::config::{Column, ReadmeConfig, SortKey};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{PuzzleId, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -20,16 +21,20 @@ impl From<std::io::Error> for Error {
     }
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parser(e) => write!(f, "{e}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 pub struct TablePosition {
     pos_start: usize,
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,26 +57,94 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Counts non-empty lines of a solution, excluding its tests.
+fn solution_lines(puzzle: PuzzleId) -> Option<usize> {
+    let solution = fs::read_to_string(puzzle.bin_path()).ok()?;
+    Some(
+        solution
+            .lines()
+            .take_while(|l| !l.starts_with("#[cfg(test)]"))
+            .filter(|l| !l.trim().is_empty())
+            .count(),
+    )
+}
+
+fn format_cell(column: Column, year: Year, timing: &Timing) -> String {
+    let code = |x: Option<String>| format!("`{}`", x.unwrap_or_else(|| "-".into()));
+    let puzzle = PuzzleId::new(year, timing.day);
+
+    match column {
+        Column::Day => format!("[Day {}](./{})", timing.day.into_inner(), puzzle.bin_path()),
+        Column::Title => puzzle.title().unwrap_or_else(|| "-".into()),
+        Column::Part1 => code(timing.part_1.clone()),
+        Column::Part2 => code(timing.part_2.clone()),
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        Column::Total => code(Some(format!(
+            "{:.1?}",
+            Duration::from_nanos(timing.total_nanos as u64)
+        ))),
+        Column::Samples => timing.samples.map_or_else(|| "-".into(), |x| x.to_string()),
+        Column::Memory => code(timing.memory_peak.clone()),
+        Column::Lines => solution_lines(puzzle).map_or_else(|| "-".into(), |x| x.to_string()),
+        Column::ParseTime => code(timing.parse_time.clone()),
+        Column::Instructions => timing
+            .instructions
+            .map_or_else(|| "-".into(), |x| x.to_string()),
+    }
+}
+
+fn sort_timings(timings: &mut [Timing], year: Year, config: &ReadmeConfig) {
+    match config.sort {
+        SortKey::Day => timings.sort_by_key(|t| t.day),
+        SortKey::Title => timings.sort_by_cached_key(|t| PuzzleId::new(year, t.day).title()),
+        SortKey::Total => {
+            timings.sort_by(|a, b| {
+                a.total_nanos
+                    .partial_cmp(&b.total_nanos)
+                    .unwrap_or(Ordering::Equal)
+            });
+        }
+        SortKey::Lines => {
+            timings.sort_by_cached_key(|t| solution_lines(PuzzleId::new(year, t.day)))
+        }
+    }
+
+    if config.descending {
+        timings.reverse();
+    }
+}
+
+fn construct_table(
+    prefix: &str,
+    year: Year,
+    mut timings: Timings,
+    total_millis: f64,
+    config: &ReadmeConfig,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    let column_headers: Vec<&str> = config.columns.iter().map(|c| c.header()).collect();
+    let column_alignments = vec![":---:"; config.columns.len()];
+
     let mut lines: Vec<String> = vec![
         MARKER.into(),
         header,
         String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        format!("| {} |", column_headers.join(" | ")),
+        format!("| {} |", column_alignments.join(" | ")),
     ];
 
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+    // NOTE: stored timings might contain days that are not part of the year's event.
+    timings.data.retain(|t| t.day.is_in(year));
+    sort_timings(&mut timings.data, year, config);
+
+    for timing in &timings.data {
+        let cells: Vec<String> = config
+            .columns
+            .iter()
+            .map(|column| format_cell(*column, year, timing))
+            .collect();
+        lines.push(format!("| {} |", cells.join(" | ")));
     }
 
     lines.push(String::new());
@@ -81,26 +154,50 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    year: Year,
+    timings: Timings,
+    total_millis: f64,
+    config: &ReadmeConfig,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", year, timings, total_millis, config);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(year: Year, timings: Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
     let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let config = ReadmeConfig::read_from_file();
+    update_content(&mut readme, year, timings, total_millis, &config)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
+/// Removes the benchmark table, keeping the marker so the table of the next year can be stored.
+pub fn reset(readme: &mut String) -> Result<(), Error> {
+    let positions = locate_table(readme)?;
+    readme.replace_range(positions.pos_start..positions.pos_end, MARKER);
+    Ok(())
+}
+
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        day,
+        template::Year,
+        template::config::{Column, ReadmeConfig, SortKey},
+        template::timings::Timing,
+        template::timings::Timings,
+    };
+
+    fn get_year() -> Year {
+        Year::new(2016).unwrap()
+    }
 
     fn get_mock_timings() -> Timings {
         Timings {
@@ -110,18 +207,33 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 },
             ],
         }
@@ -131,28 +243,63 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &ReadmeConfig::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &ReadmeConfig::default(),
+        )
+        .unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &ReadmeConfig::default(),
+        )
+        .unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &ReadmeConfig::default(),
+        )
+        .unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &ReadmeConfig::default(),
+        )
+        .unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +307,14 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(
+            &mut s,
+            get_year(),
+            get_mock_timings(),
+            190.0,
+            &ReadmeConfig::default(),
+        )
+        .unwrap();
         let expected = [
             "foo",
             "bar",
@@ -168,10 +322,10 @@ mod tests {
             "## Benchmarks",
             "",
             "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2016-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2016-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2016-04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +334,59 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_configured_columns() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let config = ReadmeConfig {
+            columns: vec![Column::Day, Column::Total, Column::Part1],
+            sort: SortKey::Total,
+            descending: true,
+        };
+        update_content(&mut s, get_year(), get_mock_timings(), 190.0, &config).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Total | Part 1 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 4](./src/bin/2016-04.rs) | `90.0s` | `40ms` |",
+            "| [Day 2](./src/bin/2016-02.rs) | `70.0s` | `30ms` |",
+            "| [Day 1](./src/bin/2016-01.rs) | `30.0s` | `10ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn format_parse_time_and_instructions() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings.data[0].parse_time = Some("2ms".into());
+        timings.data[0].instructions = Some(1_200_000);
+        let config = ReadmeConfig {
+            columns: vec![Column::Day, Column::ParseTime, Column::Instructions],
+            sort: SortKey::Day,
+            descending: false,
+        };
+        update_content(&mut s, get_year(), timings, 190.0, &config).unwrap();
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "| Day | Parse | Instructions |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](./src/bin/2016-01.rs) | `2ms` | 1200000 |",
+            "| [Day 2](./src/bin/2016-02.rs) | `-` | - |",
+            "| [Day 4](./src/bin/2016-04.rs) | `-` | - |",
+            "",
+            "**Total: 190.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
@@ -1,40 +1,66 @@
-use std::{collections::HashSet, io};
+use std::{io, process::ExitStatus};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
-
-use super::{
-    all_days,
-    timings::{Timing, Timings},
+use crate::template::{
+    ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DaySet, commands::CommandError, unix_now,
 };
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+use super::timings::{Timing, Timings};
+
+/// Runs the solutions of the given days one after another. Fails if a solution could not be run
+/// or, after running the remaining days, if one of them exited with an error.
+pub fn run_multi(
+    days_to_run: &DaySet,
+    is_release: bool,
+    is_timed: bool,
+) -> Result<Option<Timings>, CommandError> {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut failed = vec![];
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
+    // NOTE: day sets yield non-duplicate, sorted day values.
+    for puzzle in days_to_run.puzzles() {
+        let day = puzzle.day();
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        if need_space {
+            println!();
+        }
+        need_space = true;
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("------");
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+        let output = match child_commands::run_solution(puzzle, is_timed, is_release) {
+            Ok(output) => output,
+            Err(Error::Failed(_)) => {
+                failed.push(puzzle.to_string());
+                continue;
             }
-        });
+            Err(Error::IO(e)) => return Err(CommandError::Io("Failed to run cargo".into(), e)),
+            Err(Error::BrokenPipe) => {
+                return Err(CommandError::Failed(
+                    "Lost the output of the solution.".into(),
+                ));
+            }
+        };
 
-    if is_timed {
+        if output.is_empty() {
+            println!("Not solved.");
+        } else {
+            let mut val = child_commands::parse_exec_time(&output, day);
+            val.timed_at = is_timed.then(unix_now);
+            timings.push(val);
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(CommandError::Failed(format!(
+            "The solution of {} failed.",
+            failed.join(", ")
+        )));
+    }
+
+    Ok(if is_timed {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
@@ -43,7 +69,7 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         Some(timings)
     } else {
         None
-    }
+    })
 }
 
 #[allow(dead_code)]
@@ -51,6 +77,8 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
 pub enum Error {
     BrokenPipe,
     IO(io::Error),
+    /// The solution exited with an error, e.g. because it panicked.
+    Failed(ExitStatus),
 }
 
 impl From<std::io::Error> for Error {
@@ -59,16 +87,14 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{
+        Day, PuzzleId,
+        runner::{INSTRUCTIONS_PREFIX, MEMORY_PEAK_PREFIX, PARSE_LABEL},
+    };
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -77,14 +103,18 @@ pub mod child_commands {
     };
 
     /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&puzzle.bin_path()).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.bin_name();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -111,19 +141,23 @@ pub mod child_commands {
         let mut output = vec![];
 
         let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                eprintln!("{line}");
             });
         });
 
         for line in stdout.lines() {
-            let line = line.unwrap();
+            let line = line?;
             println!("{line}");
             output.push(line);
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+        thread.join().map_err(|_| Error::BrokenPipe)?;
+
+        let status = cmd.wait()?;
+        if !status.success() {
+            return Err(Error::Failed(status));
+        }
 
         Ok(output)
     }
@@ -134,8 +168,30 @@ pub mod child_commands {
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            samples: None,
+            memory_peak: None,
+            parse_time: None,
+            instructions: None,
+            timed_at: None,
         };
 
+        if let Some(memory_peak) = output
+            .iter()
+            .find_map(|l| l.strip_prefix(MEMORY_PEAK_PREFIX))
+        {
+            timings.memory_peak = Some(memory_peak.trim().into());
+        }
+
+        timings.instructions = output
+            .iter()
+            .filter_map(|l| {
+                l.strip_prefix(INSTRUCTIONS_PREFIX)?
+                    .trim()
+                    .parse::<u64>()
+                    .ok()
+            })
+            .reduce(|a, b| a + b);
+
         output
             .iter()
             .filter_map(|l| {
@@ -149,9 +205,19 @@ pub mod child_commands {
                 };
 
                 let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
+                Some((part, timing_str, nanos, parse_samples(l)))
             })
-            .for_each(|(part, timing_str, nanos)| {
+            .for_each(|(part, timing_str, nanos, samples)| {
+                // the parts parse the input themselves, their times already include parsing.
+                if part == PARSE_LABEL {
+                    timings.parse_time = Some(timing_str.into());
+                    return;
+                }
+
+                if let Some(samples) = samples {
+                    timings.samples = Some(timings.samples.map_or(samples, |s| s.min(samples)));
+                }
+
                 if part.contains("Part 1") {
                     timings.part_1 = Some(timing_str.into());
                 } else if part.contains("Part 2") {
@@ -164,6 +230,16 @@ pub mod child_commands {
         timings
     }
 
+    fn parse_samples(line: &str) -> Option<u128> {
+        line.split(" samples)")
+            .next()?
+            .split('@')
+            .next_back()?
+            .trim()
+            .parse()
+            .ok()
+    }
+
     fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
         s.split(postfix).next()?.parse().ok()
     }
@@ -222,6 +298,43 @@ pub mod child_commands {
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
             assert_eq!(res.part_1.unwrap(), "74.13ns");
             assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.samples, Some(99999));
+            assert_eq!(res.memory_peak, None);
+            assert_eq!(res.parse_time, None);
+            assert_eq!(res.instructions, None);
+        }
+
+        #[test]
+        fn parses_memory_peak() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
+                    "Peak memory: 2.4 MiB".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.memory_peak.unwrap(), "2.4 MiB");
+        }
+
+        #[test]
+        fn parses_parse_time_and_instructions() {
+            let res = parse_exec_time(
+                &[
+                    "Parse: (1.5ms @ 600 samples)".into(),
+                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
+                    "Instructions: 1200".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
+                    "Instructions: 34".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_eq!(res.parse_time.unwrap(), "1.5ms");
+            assert_eq!(res.instructions, Some(1234));
+            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
+            assert_eq!(res.samples, Some(99999));
         }
 
         #[test]
@@ -2,52 +2,145 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::backend::Backend;
+use crate::template::config::{BenchConfig, SubmissionConfig};
+use crate::template::perf::count_instructions;
+use crate::template::submission::{SubmissionLog, SubmissionOutcome};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, PuzzleId, unix_now};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Prefix of the line that reports the peak memory usage of a benched solution.
+pub const MEMORY_PEAK_PREFIX: &str = "Peak memory:";
+
+/// Prefix of the line that reports the instructions a benched part retired.
+pub const INSTRUCTIONS_PREFIX: &str = "Instructions:";
+
+/// Label of the timing line of the parse step.
+pub const PARSE_LABEL: &str = "Parse";
+
+/// Runs the parse step of a solution and prints how long it takes. Its result is discarded, the
+/// parts parse the input themselves.
+pub fn run_parse<I: Copy, T>(func: impl Fn(I) -> T, input: I) {
+    let (_, duration, samples, _) = run_timed(func, input, |_| print!("{PARSE_LABEL}:"));
+    print!("\r");
+    println!("{PARSE_LABEL}:{}", format_duration(&duration, samples));
+}
+
+/// Runs a solution part and prints its result. Returns the outcome if the result was submitted.
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: u8,
+) -> Option<SubmissionOutcome> {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
+    let (result, duration, samples, instructions) =
         run_timed(func, input, |result| print_result(result, &part_str, ""));
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if let Some(instructions) = instructions {
+        println!("{INSTRUCTIONS_PREFIX} {instructions}");
+    }
+
+    submit_result(result?, puzzle, part)
+}
+
+/// Exits with an error if a submitted answer was rejected, so that `cargo solve --submit` fails.
+pub fn exit_on_wrong_answer(outcomes: &[Option<SubmissionOutcome>]) {
+    if outcomes.iter().flatten().any(SubmissionOutcome::is_wrong) {
+        process::exit(1);
     }
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// When benching, also returns the instructions of the first execution if the cpu counters can be read.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = {
+) -> (T, Duration, u128, Option<u64>) {
+    let ((result, base_time), instructions) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        let run = || {
+            let timer = Instant::now();
+            let result = func(input);
+            (result, timer.elapsed())
+        };
+
+        if is_timed() {
+            count_instructions(run)
+        } else {
+            (run(), None)
+        }
     };
-    let base_time = timer.elapsed();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if is_timed() {
         bench(func, input, &base_time)
     } else {
         (base_time, 1)
     };
 
-    (result, run.0, run.1)
+    (result, run.0, run.1, instructions)
+}
+
+fn is_timed() -> bool {
+    env::args().any(|x| x == "--time")
+}
+
+/// Print the peak memory usage of the solution process when benching.
+/// Only supported on platforms that expose `/proc/self/status`.
+pub fn print_peak_memory() {
+    if !is_timed() {
+        return;
+    }
+
+    if let Some(bytes) = peak_memory_bytes() {
+        println!("{MEMORY_PEAK_PREFIX} {}", format_bytes(bytes));
+    }
+}
+
+fn peak_memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kilobytes: u64 = status
+        .lines()
+        .find_map(|l| l.strip_prefix("VmHWM:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.1} {}", UNITS[unit])
+    }
 }
 
 fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
@@ -56,8 +149,9 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations =
-        (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
+    let config = BenchConfig::read_from_file();
+    let bench_iterations = (config.budget.as_nanos() / cmp::max(base_time.as_nanos(), 10))
+        .clamp(config.min_samples, config.max_samples);
 
     let mut timers: Vec<Duration> = vec![];
 
@@ -127,12 +221,9 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. a session cookie is available or aoc-cli is installed.
+///  3. the submission log does not show that the answer will fail, unless `--force` is passed.
+fn submit_result<T: Display>(result: T, puzzle: PuzzleId, part: u8) -> Option<SubmissionOutcome> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,13 +246,45 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
-        process::exit(1);
+    let backend = match Backend::detect() {
+        Ok(backend) => backend,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    let answer = result.to_string();
+    let config = SubmissionConfig::read_from_file();
+    let mut log = SubmissionLog::read_from_file(puzzle);
+
+    if config.guard
+        && !args.contains(&"--force".into())
+        && let Err(reason) = log.check(part, &answer, unix_now())
+    {
+        println!("🛑 Not submitting: {reason} Append `--force` to submit anyway.");
+        return None;
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    match backend.submit(puzzle, part, &answer) {
+        Ok(outcome) => {
+            println!("{outcome}");
+            log.record(
+                part,
+                &answer,
+                outcome.clone(),
+                unix_now(),
+                config.cooldown_secs,
+            );
+            if let Err(e) = log.store_file(puzzle) {
+                eprintln!("failed to store submission log: {e}");
+            }
+            Some(outcome)
+        }
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            process::exit(1);
+        }
+    }
 }
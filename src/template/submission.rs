@@ -0,0 +1,553 @@
+/// Interprets the responses of advent of code to submitted answers and keeps a log of them.
+use std::{
+    collections::HashMap, fmt::Display, fs, io::Error, path::Path, str::FromStr, time::Duration,
+};
+
+use tinyjson::JsonValue;
+
+use crate::template::PuzzleId;
+
+/// The verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// The answer is wrong, without a hint in which direction.
+    Incorrect,
+    /// The part was already solved, the answer was not checked.
+    AlreadySolved,
+    /// An answer was submitted too recently, the answer was not checked.
+    RateLimited(Option<Duration>),
+    /// The response could not be interpreted. Contains the response message.
+    Unknown(String),
+}
+
+impl SubmissionOutcome {
+    /// Parses the response message, either as printed by aoc-cli or as extracted from the answer page.
+    pub fn parse(message: &str) -> Self {
+        let normalized = message.to_lowercase().replace('\n', " ");
+
+        if normalized.contains("that's the right answer") {
+            SubmissionOutcome::Correct
+        } else if normalized.contains("that's not the right answer") {
+            if normalized.contains("your answer is too high") {
+                SubmissionOutcome::TooHigh
+            } else if normalized.contains("your answer is too low") {
+                SubmissionOutcome::TooLow
+            } else {
+                SubmissionOutcome::Incorrect
+            }
+        } else if normalized.contains("you gave an answer too recently") {
+            SubmissionOutcome::RateLimited(parse_wait(&normalized))
+        } else if normalized.contains("did you already complete it") {
+            SubmissionOutcome::AlreadySolved
+        } else {
+            SubmissionOutcome::Unknown(message.trim().to_string())
+        }
+    }
+
+    pub fn is_correct(&self) -> bool {
+        matches!(self, SubmissionOutcome::Correct)
+    }
+
+    /// Whether the answer was checked and rejected.
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            SubmissionOutcome::TooHigh | SubmissionOutcome::TooLow | SubmissionOutcome::Incorrect
+        )
+    }
+
+    /// Key of the outcome in the submission log.
+    fn key(&self) -> &'static str {
+        match self {
+            SubmissionOutcome::Correct => "correct",
+            SubmissionOutcome::TooHigh => "too_high",
+            SubmissionOutcome::TooLow => "too_low",
+            SubmissionOutcome::Incorrect => "incorrect",
+            SubmissionOutcome::AlreadySolved => "already_solved",
+            SubmissionOutcome::RateLimited(_) => "rate_limited",
+            SubmissionOutcome::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "correct" => Some(SubmissionOutcome::Correct),
+            "too_high" => Some(SubmissionOutcome::TooHigh),
+            "too_low" => Some(SubmissionOutcome::TooLow),
+            "incorrect" => Some(SubmissionOutcome::Incorrect),
+            "already_solved" => Some(SubmissionOutcome::AlreadySolved),
+            "rate_limited" => Some(SubmissionOutcome::RateLimited(None)),
+            "unknown" => Some(SubmissionOutcome::Unknown(String::new())),
+            _ => None,
+        }
+    }
+}
+
+/// Parses the remaining wait time of a message like `You have 1m 5s left to wait.`
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.rfind("you have ")? + "you have ".len();
+    let end = start + message[start..].find(" left to wait")?;
+
+    message[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for SubmissionOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionOutcome::Correct => write!(f, "⭐️ That's the right answer!"),
+            SubmissionOutcome::TooHigh => {
+                write!(f, "❌ That's not the right answer, it is too high.")
+            }
+            SubmissionOutcome::TooLow => {
+                write!(f, "❌ That's not the right answer, it is too low.")
+            }
+            SubmissionOutcome::Incorrect => write!(f, "❌ That's not the right answer."),
+            SubmissionOutcome::AlreadySolved => {
+                write!(
+                    f,
+                    "🎄 This part is already solved, the answer was not checked."
+                )
+            }
+            SubmissionOutcome::RateLimited(Some(wait)) => write!(
+                f,
+                "⏳ An answer was submitted too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            SubmissionOutcome::RateLimited(None) => write!(
+                f,
+                "⏳ An answer was submitted too recently, wait a bit before trying again."
+            ),
+            SubmissionOutcome::Unknown(message) => {
+                write!(f, "Could not interpret the response:\n{message}")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A submitted answer and its outcome.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: SubmissionOutcome,
+    /// Unix timestamp of the submission.
+    pub timestamp: u64,
+    /// Unix timestamp until which no answers are accepted.
+    pub cooldown_until: Option<u64>,
+}
+
+/// Reasons to not submit an answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SubmissionBlocked {
+    Empty,
+    MultiLine,
+    AlreadyRejected(SubmissionOutcome),
+    AboveUpperBound(String),
+    BelowLowerBound(String),
+    Cooldown(u64),
+}
+
+impl Display for SubmissionBlocked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionBlocked::Empty => write!(f, "the answer is empty."),
+            SubmissionBlocked::MultiLine => write!(f, "the answer spans multiple lines."),
+            SubmissionBlocked::AlreadyRejected(outcome) => {
+                write!(f, "this answer was already rejected ({}).", outcome.key())
+            }
+            SubmissionBlocked::AboveUpperBound(bound) => {
+                write!(
+                    f,
+                    "the answer is not lower than {bound}, which was too high."
+                )
+            }
+            SubmissionBlocked::BelowLowerBound(bound) => {
+                write!(
+                    f,
+                    "the answer is not higher than {bound}, which was too low."
+                )
+            }
+            SubmissionBlocked::Cooldown(secs) => {
+                write!(f, "answers are blocked for another {secs}s.")
+            }
+        }
+    }
+}
+
+/// The answers submitted for a puzzle, stored in `data/<year>/submissions/<day>.json`.
+/// Used to avoid submissions that are known to fail.
+#[derive(Debug, Clone, Default)]
+pub struct SubmissionLog {
+    pub data: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn file_path(puzzle: PuzzleId) -> String {
+        format!("{}/{}.json", puzzle.data_dir("submissions"), puzzle.day())
+    }
+
+    /// Rehydrate the log from its JSON file. If not present, returns an empty log.
+    pub fn read_from_file(puzzle: PuzzleId) -> Self {
+        fs::read_to_string(Self::file_path(puzzle))
+            .map_err(|x| x.to_string())
+            .and_then(SubmissionLog::try_from)
+            .unwrap_or_default()
+    }
+
+    /// Dehydrate the log to its JSON file.
+    pub fn store_file(&self, puzzle: PuzzleId) -> Result<(), Error> {
+        let path = Self::file_path(puzzle);
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Checks an answer against the previous submissions of a part.
+    pub fn check(&self, part: u8, answer: &str, now: u64) -> Result<(), SubmissionBlocked> {
+        if answer.trim().is_empty() {
+            return Err(SubmissionBlocked::Empty);
+        }
+
+        if answer.trim().contains('\n') {
+            return Err(SubmissionBlocked::MultiLine);
+        }
+
+        if let Some(until) = self.data.iter().filter_map(|s| s.cooldown_until).max()
+            && until > now
+        {
+            return Err(SubmissionBlocked::Cooldown(until - now));
+        }
+
+        let submissions: Vec<&Submission> = self.data.iter().filter(|s| s.part == part).collect();
+
+        if let Some(rejected) = submissions
+            .iter()
+            .find(|s| s.answer == answer && s.outcome.is_wrong())
+        {
+            return Err(SubmissionBlocked::AlreadyRejected(rejected.outcome.clone()));
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let bound = |outcome: SubmissionOutcome| {
+                submissions
+                    .iter()
+                    .filter(move |s| s.outcome == outcome)
+                    .filter_map(|s| s.answer.parse::<i128>().ok())
+            };
+
+            if let Some(upper) = bound(SubmissionOutcome::TooHigh).min()
+                && value >= upper
+            {
+                return Err(SubmissionBlocked::AboveUpperBound(upper.to_string()));
+            }
+
+            if let Some(lower) = bound(SubmissionOutcome::TooLow).max()
+                && value <= lower
+            {
+                return Err(SubmissionBlocked::BelowLowerBound(lower.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the outcome of a submission, including the cooldown it causes. Wrong answers cause
+    /// a cooldown of `wrong_answer_cooldown` seconds.
+    pub fn record(
+        &mut self,
+        part: u8,
+        answer: &str,
+        outcome: SubmissionOutcome,
+        now: u64,
+        wrong_answer_cooldown: u64,
+    ) {
+        let cooldown_until = match &outcome {
+            SubmissionOutcome::RateLimited(Some(wait)) => Some(now + wait.as_secs()),
+            outcome if outcome.is_wrong() => Some(now + wrong_answer_cooldown),
+            _ => None,
+        };
+
+        self.data.push(Submission {
+            part,
+            answer: answer.to_string(),
+            outcome,
+            timestamp: now,
+            cooldown_until,
+        });
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<SubmissionLog> for JsonValue {
+    fn from(value: SubmissionLog) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for SubmissionLog {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(SubmissionLog {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "outcome".into(),
+            JsonValue::String(value.outcome.key().into()),
+        );
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "cooldown_until".into(),
+            match value.cooldown_until {
+                Some(x) => JsonValue::Number(x as f64),
+                None => JsonValue::Null,
+            },
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| json.get(key).and_then(|v| v.get::<f64>().copied());
+
+        let part = number("part")
+            .map(|x| x as u8)
+            .ok_or("Expected submission.part to be a number.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .cloned()
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let outcome = json
+            .get("outcome")
+            .and_then(|v| v.get::<String>())
+            .and_then(|x| SubmissionOutcome::from_key(x))
+            .ok_or("Expected submission.outcome to be a known outcome.")?;
+
+        let timestamp = number("timestamp")
+            .map(|x| x as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        let cooldown_until = number("cooldown_until").map(|x| x as u64);
+
+        Ok(Submission {
+            part,
+            answer,
+            outcome,
+            timestamp,
+            cooldown_until,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{SubmissionBlocked, SubmissionLog, SubmissionOutcome};
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "That's the right answer! You are one gold star closer to finding the Chief Historian."
+            ),
+            SubmissionOutcome::Correct
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "That's not the right answer; your answer is too high. If you're stuck, make sure you're using the full input data."
+            ),
+            SubmissionOutcome::TooHigh
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("That's not the right answer;\nyour answer is too low."),
+            SubmissionOutcome::TooLow
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "That's not the right answer. If you're stuck, make sure you're using the full input data."
+            ),
+            SubmissionOutcome::Incorrect
+        );
+    }
+
+    #[test]
+    fn parses_already_solved() {
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You don't seem to be solving the right level. Did you already complete it? [Return to Day 1]"
+            ),
+            SubmissionOutcome::AlreadySolved
+        );
+    }
+
+    #[test]
+    fn parses_rate_limits() {
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 47s left to wait."
+            ),
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(47)))
+        );
+        assert_eq!(
+            SubmissionOutcome::parse(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 5s left to wait."
+            ),
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            SubmissionOutcome::parse("You gave an answer too recently."),
+            SubmissionOutcome::RateLimited(None)
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_messages() {
+        assert_eq!(
+            SubmissionOutcome::parse(" Something else. "),
+            SubmissionOutcome::Unknown("Something else.".into())
+        );
+    }
+
+    fn log() -> SubmissionLog {
+        let mut log = SubmissionLog::default();
+        log.record(1, "100", SubmissionOutcome::TooHigh, 1000, 60);
+        log.record(1, "10", SubmissionOutcome::TooLow, 1100, 60);
+        log.record(1, "50", SubmissionOutcome::Incorrect, 1200, 60);
+        log
+    }
+
+    #[test]
+    fn blocks_invalid_answers() {
+        let log = SubmissionLog::default();
+        assert_eq!(log.check(1, " ", 0), Err(SubmissionBlocked::Empty));
+        assert_eq!(log.check(1, "1\n2", 0), Err(SubmissionBlocked::MultiLine));
+        assert_eq!(log.check(1, "42", 0), Ok(()));
+    }
+
+    #[test]
+    fn blocks_rejected_answers() {
+        assert_eq!(
+            log().check(1, "50", 2000),
+            Err(SubmissionBlocked::AlreadyRejected(
+                SubmissionOutcome::Incorrect
+            ))
+        );
+        assert_eq!(log().check(2, "50", 2000), Ok(()));
+    }
+
+    #[test]
+    fn blocks_answers_outside_bounds() {
+        assert_eq!(
+            log().check(1, "120", 2000),
+            Err(SubmissionBlocked::AboveUpperBound("100".into()))
+        );
+        assert_eq!(
+            log().check(1, "10", 2000),
+            Err(SubmissionBlocked::AlreadyRejected(
+                SubmissionOutcome::TooLow
+            ))
+        );
+        assert_eq!(
+            log().check(1, "5", 2000),
+            Err(SubmissionBlocked::BelowLowerBound("10".into()))
+        );
+        assert_eq!(log().check(1, "42", 2000), Ok(()));
+        assert_eq!(log().check(1, "foo", 2000), Ok(()));
+    }
+
+    #[test]
+    fn blocks_during_cooldown() {
+        assert_eq!(
+            log().check(1, "42", 1230),
+            Err(SubmissionBlocked::Cooldown(30))
+        );
+
+        let mut log = SubmissionLog::default();
+        log.record(
+            2,
+            "7",
+            SubmissionOutcome::RateLimited(Some(Duration::from_secs(300))),
+            0,
+            60,
+        );
+        assert_eq!(log.check(2, "8", 299), Err(SubmissionBlocked::Cooldown(1)));
+        assert_eq!(log.check(2, "8", 300), Ok(()));
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let json = tinyjson::JsonValue::from(log()).stringify().unwrap();
+        let parsed = SubmissionLog::try_from(json).unwrap();
+        assert_eq!(parsed.data, log().data);
+    }
+}
@@ -1,9 +1,7 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, path::Path, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
-
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+use crate::template::{Day, Year, config};
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
@@ -12,6 +10,16 @@ pub struct Timing {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Lowest sample count of the benched parts.
+    pub samples: Option<u128>,
+    /// Peak memory usage of the solution process, formatted for display.
+    pub memory_peak: Option<String>,
+    /// Duration of the parse step, for solutions that declare one.
+    pub parse_time: Option<String>,
+    /// Instructions retired by both parts, if the cpu counters could be read.
+    pub instructions: Option<u64>,
+    /// Unix timestamp of when the day was timed.
+    pub timed_at: Option<u64>,
 }
 
 /// Represents benchmark times for a set of days.
@@ -22,16 +30,36 @@ pub struct Timings {
 }
 
 impl Timings {
+    /// Path of the timings file of an event year, e.g. `./data/2025/timings.json`.
+    /// Single-year repositories keep it in `./data/timings.json`, which is used for the configured year if present.
+    pub fn file_path(year: Year) -> String {
+        let path = format!("{}/{year}/timings.json", config::data_dir());
+        let legacy_path = format!("{}/timings.json", config::data_dir());
+
+        if year == Year::configured()
+            && !Path::new(&path).exists()
+            && Path::new(&legacy_path).exists()
+        {
+            legacy_path
+        } else {
+            path
+        }
+    }
+
     /// Dehydrate timings to a JSON file.
-    pub fn store_file(&self) -> Result<(), Error> {
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let path = Self::file_path(year);
+        if let Some(parent) = Path::new(&path).parent() {
+            fs::create_dir_all(parent)?;
+        }
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+    pub fn read_from_file(year: Year) -> Self {
+        fs::read_to_string(Self::file_path(year))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -51,7 +79,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|t| t.day);
         Timings { data }
     }
 
@@ -60,10 +88,14 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Whether both parts of a day are benched. Days without a puzzle in the year's event are
+    /// always complete as there is nothing to bench.
+    pub fn is_day_complete(&self, year: Year, day: Day) -> bool {
+        !day.is_in(year)
+            || self
+                .data
+                .iter()
+                .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
     }
 }
 
@@ -133,6 +165,32 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        if let Some(samples) = value.samples {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert("samples".into(), JsonValue::Number(samples as f64));
+        }
+
+        if let Some(memory_peak) = value.memory_peak.clone() {
+            map.insert("memory_peak".into(), JsonValue::String(memory_peak));
+        }
+
+        if let Some(parse_time) = value.parse_time.clone() {
+            map.insert("parse_time".into(), JsonValue::String(parse_time));
+        }
+
+        if let Some(instructions) = value.instructions {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert(
+                "instructions".into(),
+                JsonValue::Number(instructions as f64),
+            );
+        }
+
+        if let Some(timed_at) = value.timed_at {
+            #[allow(clippy::cast_precision_loss)]
+            map.insert("timed_at".into(), JsonValue::Number(timed_at as f64));
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -166,11 +224,70 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        // NOTE: `samples`, `memory_peak`, `parse_time`, `instructions` and `timed_at` were added later and are optional for backwards compatibility.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = match json.get("samples") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|x| *x as u128)
+                    .ok_or("Expected timing.samples to be null or a number.")?,
+            ),
+        };
+
+        let memory_peak = match json.get("memory_peak") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected timing.memory_peak to be null or string.")?,
+            ),
+        };
+
+        let parse_time = match json.get("parse_time") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .cloned()
+                    .ok_or("Expected timing.parse_time to be null or string.")?,
+            ),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let instructions = match json.get("instructions") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|x| *x as u64)
+                    .ok_or("Expected timing.instructions to be null or a number.")?,
+            ),
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timed_at = match json.get("timed_at") {
+            None => None,
+            Some(v) if v.is_null() => None,
+            Some(v) => Some(
+                v.get::<f64>()
+                    .map(|x| *x as u64)
+                    .ok_or("Expected timing.timed_at to be null or a number.")?,
+            ),
+        };
+
         Ok(Timing {
             day,
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            samples,
+            memory_peak,
+            parse_time,
+            instructions,
+            timed_at,
         })
     }
 }
@@ -191,18 +308,33 @@ mod tests {
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 },
                 Timing {
                     day: day!(2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 },
                 Timing {
                     day: day!(4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 },
             ],
         }
@@ -223,6 +355,18 @@ mod tests {
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_timings_with_metrics() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": "2ms", "total_nanos": 3000000, "samples": 400, "memory_peak": "1.5 MiB", "parse_time": "0.5ms", "instructions": 123456, "timed_at": 1764565200 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.samples, Some(400));
+            assert_eq!(timing.memory_peak, Some("1.5 MiB".to_string()));
+            assert_eq!(timing.parse_time, Some("0.5ms".to_string()));
+            assert_eq!(timing.instructions, Some(123_456));
+            assert_eq!(timing.timed_at, Some(1_764_565_200));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -271,6 +415,7 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
+            template::Year,
             template::timings::{Timing, Timings},
         };
 
@@ -282,10 +427,18 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(
+                timings.is_day_complete(Year::new(2024).unwrap(), day!(1)),
+                true
+            );
         }
 
         #[test]
@@ -296,10 +449,18 @@ mod tests {
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(Year::new(2024).unwrap(), day!(1)),
+                false
+            );
         }
 
         #[test]
@@ -310,10 +471,26 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(Year::new(2024).unwrap(), day!(1)),
+                false
+            );
+        }
+
+        #[test]
+        fn handles_days_outside_of_event() {
+            let timings = Timings::default();
+
+            assert!(timings.is_day_complete(Year::new(2025).unwrap(), day!(13)));
+            assert!(!timings.is_day_complete(Year::new(2024).unwrap(), day!(13)));
         }
     }
 
@@ -334,6 +511,11 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 }],
             };
             let merged = timings.merge(&other);
@@ -354,6 +536,11 @@ mod tests {
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    samples: None,
+                    memory_peak: None,
+                    parse_time: None,
+                    instructions: None,
+                    timed_at: None,
                 }],
             };
             let merged = timings.merge(&other);
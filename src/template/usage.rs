@@ -0,0 +1,452 @@
+//! Describes the commands of the CLI. The descriptions drive the help output, the validation of
+//! arguments and the generated shell completions.
+use std::{error::Error, fmt::Display};
+
+/// The value a flag takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    None,
+    /// A value in the same or the next argument, e.g. `--year 2024` or `--year=2024`. Values named
+    /// `template` and `path` are completed with the templates and files respectively.
+    Required(&'static str),
+    /// A value that can only be attached with `=`, e.g. `--overwrite=module`.
+    Optional(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Flag {
+    pub name: &'static str,
+    pub value: Value,
+    pub help: &'static str,
+}
+
+/// The free-standing arguments a command takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Positional {
+    None,
+    /// A selection of days, completed with the scaffolded days.
+    Days {
+        required: bool,
+    },
+    /// A single puzzle, completed with the scaffolded days.
+    Puzzle,
+    /// One of a fixed set of values.
+    OneOf(&'static [&'static str]),
+    /// The name of a command.
+    Command,
+    /// Any other value, e.g. an id.
+    Other(&'static str),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Command {
+    pub name: &'static str,
+    pub about: &'static str,
+    pub positional: Positional,
+    pub flags: &'static [Flag],
+}
+
+const fn flag(name: &'static str, help: &'static str) -> Flag {
+    Flag {
+        name,
+        value: Value::None,
+        help,
+    }
+}
+
+const fn option(name: &'static str, value: &'static str, help: &'static str) -> Flag {
+    Flag {
+        name,
+        value: Value::Required(value),
+        help,
+    }
+}
+
+/// Flags that every command accepts.
+pub const GLOBAL_FLAGS: &[Flag] = &[
+    option(
+        "--year",
+        "year",
+        "Selects the event of plain days, defaults to the year of aoc.toml.",
+    ),
+    flag("--help", "Prints the help of the command."),
+];
+
+pub const SHELLS: &[&str] = &["bash", "zsh", "fish"];
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "scaffold",
+        about: "Creates the solution modules, inputs and example files of the selected days.",
+        positional: Positional::Days { required: true },
+        flags: &[
+            flag(
+                "--download",
+                "Downloads the inputs and descriptions as well.",
+            ),
+            option(
+                "--template",
+                "template",
+                "Creates the modules from templates/<template>.txt.",
+            ),
+            Flag {
+                name: "--overwrite",
+                value: Value::Optional("files"),
+                help: "Replaces existing files: module (default), input, example or all.",
+            },
+            flag("--dry-run", "Only prints what would change."),
+        ],
+    },
+    Command {
+        name: "download",
+        about: "Downloads the inputs and descriptions of the selected days.",
+        positional: Positional::Days { required: false },
+        flags: &[
+            flag("--all", "Downloads every day of the event."),
+            flag(
+                "--force",
+                "Downloads days that already have an input again.",
+            ),
+        ],
+    },
+    Command {
+        name: "read",
+        about: "Shows the description of a puzzle in the terminal.",
+        positional: Positional::Puzzle,
+        flags: &[flag("--page", "Shows the description in $PAGER.")],
+    },
+    Command {
+        name: "leaderboard",
+        about: "Shows a private leaderboard.",
+        positional: Positional::Other("id"),
+        flags: &[
+            option(
+                "--day",
+                "day",
+                "Shows the completion times of a day, defaults to the latest day.",
+            ),
+            option(
+                "--fixture",
+                "path",
+                "Reads the leaderboard from a JSON file instead of fetching it.",
+            ),
+        ],
+    },
+    Command {
+        name: "solve",
+        about: "Runs the solutions of the selected days.",
+        positional: Positional::Days { required: true },
+        flags: &[
+            flag("--release", "Runs an optimized build."),
+            flag("--dhat", "Profiles the heap allocations with DHAT."),
+            option("--submit", "part", "Submits the answer of a part."),
+            flag(
+                "--force",
+                "Submits answers that were rejected before or are on cooldown.",
+            ),
+            flag("--watch", "Reruns the solution whenever its files change."),
+            flag(
+                "--tests",
+                "Reruns the tests instead of the solution with --watch.",
+            ),
+        ],
+    },
+    Command {
+        name: "all",
+        about: "Runs the solutions of all or the selected days.",
+        positional: Positional::Days { required: false },
+        flags: &[flag("--release", "Runs an optimized build.")],
+    },
+    Command {
+        name: "time",
+        about: "Benchmarks the solutions that were not benchmarked yet, or the selected days.",
+        positional: Positional::Days { required: false },
+        flags: &[
+            flag("--all", "Benchmarks all solutions."),
+            flag("--store", "Stores the timings in the readme."),
+        ],
+    },
+    Command {
+        name: "status",
+        about: "Shows which files, answers and timings of each day of the event exist.",
+        positional: Positional::None,
+        flags: &[],
+    },
+    Command {
+        name: "privacy-check",
+        about: "Checks that no inputs or puzzle descriptions are tracked by git.",
+        positional: Positional::None,
+        flags: &[flag(
+            "--fix",
+            "Untracks them and adds the missing rules to .gitignore.",
+        )],
+    },
+    Command {
+        name: "archive-year",
+        about: "Moves the files of the configured year into year-namespaced paths and starts the next year.",
+        positional: Positional::None,
+        flags: &[
+            option(
+                "--next",
+                "year",
+                "The year to configure afterwards, defaults to the following year.",
+            ),
+            flag("--dry-run", "Prints the files that would be moved."),
+        ],
+    },
+    Command {
+        name: "check-days",
+        about: "Runs the tests of all or the selected days and prints a table of their results.",
+        positional: Positional::Days { required: false },
+        flags: &[],
+    },
+    #[cfg(feature = "today")]
+    Command {
+        name: "today",
+        about: "Scaffolds, downloads and shows the puzzle of the current day.",
+        positional: Positional::None,
+        flags: &[flag("--wait", "Waits for the next puzzle to unlock.")],
+    },
+    Command {
+        name: "completions",
+        about: "Prints a completion script for bash, zsh or fish.",
+        positional: Positional::OneOf(SHELLS),
+        flags: &[],
+    },
+    Command {
+        name: "help",
+        about: "Prints the help of a command.",
+        positional: Positional::Command,
+        flags: &[],
+    },
+];
+
+pub fn find_command(name: &str) -> Option<&'static Command> {
+    COMMANDS.iter().find(|c| c.name == name)
+}
+
+impl Command {
+    /// The flags of this command, including the global flags.
+    pub fn all_flags(&self) -> impl Iterator<Item = &'static Flag> + use<> {
+        self.flags.iter().chain(GLOBAL_FLAGS)
+    }
+
+    fn find_flag(&self, name: &str) -> Option<&'static Flag> {
+        self.all_flags().find(|f| f.name == name)
+    }
+
+    fn usage(&self) -> String {
+        let positional = match self.positional {
+            Positional::None => String::new(),
+            Positional::Days { required: true } => " <days>".into(),
+            Positional::Days { required: false } => " [days]".into(),
+            Positional::Puzzle => " <day>".into(),
+            Positional::OneOf(values) => format!(" <{}>", values.join("|")),
+            Positional::Command => " [command]".into(),
+            Positional::Other(name) => format!(" <{name}>"),
+        };
+
+        format!("cargo {}{positional} [options]", self.name)
+    }
+
+    /// The usage, description and flags of this command.
+    pub fn help(&self) -> String {
+        let mut out = format!("Usage: {}\n\n{}\n\nOptions:\n", self.usage(), self.about);
+
+        for flag in self.all_flags() {
+            let name = match flag.value {
+                Value::None => flag.name.to_string(),
+                Value::Required(value) => format!("{} <{value}>", flag.name),
+                Value::Optional(value) => format!("{}[=<{value}>]", flag.name),
+            };
+            out.push_str(&format!("  {name:<24}{}\n", flag.help));
+        }
+
+        out
+    }
+}
+
+/// Lists all commands.
+pub fn help() -> String {
+    let mut out = String::from("Usage: cargo <command> [options]\n\nCommands:\n");
+
+    for command in COMMANDS {
+        out.push_str(&format!("  {:<14}{}\n", command.name, command.about));
+    }
+
+    out.push_str("\nRun `cargo <command> --help` for the options of a command.\n");
+    out
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which can be returned when validating the arguments of a command.
+#[derive(Debug, PartialEq, Eq)]
+pub enum UsageError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownFlag(&'static Command, String),
+    MissingValue(String),
+    UnexpectedValue(String),
+    UnexpectedArgument(String),
+    /// Flags that cannot be used together, with a message explaining how they can be combined.
+    InvalidCombination(&'static str),
+}
+
+impl Error for UsageError {}
+
+impl Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UsageError::MissingCommand => {
+                write!(f, "no command specified.\n\n{}", help())
+            }
+            UsageError::UnknownCommand(name) => {
+                write!(f, "unknown command `{name}`.")?;
+                let names = COMMANDS.iter().map(|c| c.name);
+                if let Some(suggestion) = suggest(name, names) {
+                    write!(f, " Did you mean `{suggestion}`?")?;
+                }
+                write!(f, "\n\n{}", help())
+            }
+            UsageError::UnknownFlag(command, name) => {
+                write!(f, "unknown option `{name}` for `{}`.", command.name)?;
+                if let Some(suggestion) = suggest(name, command.all_flags().map(|f| f.name)) {
+                    write!(f, " Did you mean `{suggestion}`?")?;
+                }
+                write!(f, "\n\n{}", command.help())
+            }
+            UsageError::MissingValue(name) => write!(f, "option `{name}` requires a value."),
+            UsageError::UnexpectedValue(name) => {
+                write!(f, "option `{name}` does not take a value.")
+            }
+            UsageError::UnexpectedArgument(arg) => write!(f, "unexpected argument `{arg}`."),
+            UsageError::InvalidCombination(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+/// Checks that all flags of the arguments following a command exist and get a value if they need one.
+pub fn validate(command: &'static Command, args: &[String]) -> Result<(), UsageError> {
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "-h" || !arg.starts_with("--") {
+            continue;
+        }
+
+        let (name, value) = match arg.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (arg.as_str(), None),
+        };
+
+        let flag = command
+            .find_flag(name)
+            .ok_or_else(|| UsageError::UnknownFlag(command, name.to_string()))?;
+
+        match (flag.value, value) {
+            (Value::None, Some(_)) => return Err(UsageError::UnexpectedValue(name.into())),
+            (Value::Required(_), None) => {
+                args.next()
+                    .ok_or_else(|| UsageError::MissingValue(name.into()))?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the candidate closest to a mistyped `input`, if any is close enough.
+pub fn suggest<'a>(input: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, candidate)| *distance <= 2.max(candidate.len() / 4))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance of two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{COMMANDS, UsageError, edit_distance, find_command, suggest, validate};
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn suggests_close_names() {
+        assert_eq!(edit_distance("scafold", "scaffold"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        let names = || COMMANDS.iter().map(|c| c.name);
+        assert_eq!(suggest("scafold", names()), Some("scaffold"));
+        assert_eq!(suggest("slove", names()), Some("solve"));
+        assert_eq!(suggest("xyz", names()), None);
+    }
+
+    #[test]
+    fn validates_flags() {
+        let scaffold = find_command("scaffold").unwrap();
+
+        assert!(
+            validate(
+                scaffold,
+                &args("1-5 --download --overwrite=module --year 2024")
+            )
+            .is_ok()
+        );
+        assert!(validate(scaffold, &args("1 --template grid")).is_ok());
+        assert_eq!(
+            validate(scaffold, &args("1 --dowload")),
+            Err(UsageError::UnknownFlag(scaffold, "--dowload".into()))
+        );
+        assert_eq!(
+            validate(scaffold, &args("1 --template")),
+            Err(UsageError::MissingValue("--template".into()))
+        );
+        assert_eq!(
+            validate(scaffold, &args("1 --download=yes")),
+            Err(UsageError::UnexpectedValue("--download".into()))
+        );
+    }
+
+    #[test]
+    fn mentions_suggestion_in_error() {
+        let solve = find_command("solve").unwrap();
+        let error = validate(solve, &args("1 --sumbit 1")).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("unknown option `--sumbit` for `solve`. Did you mean `--submit`?")
+        );
+    }
+
+    #[test]
+    fn lists_flags_in_help() {
+        let help = find_command("scaffold").unwrap().help();
+        assert!(help.starts_with("Usage: cargo scaffold <days> [options]\n"));
+        assert!(help.contains("  --overwrite[=<files>]   "));
+        assert!(help.contains("  --year <year>           "));
+    }
+}
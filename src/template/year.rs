@@ -0,0 +1,201 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{config, unix_now};
+
+/// Number of puzzles per event, keyed by the first year the count applies to.
+/// Can be overridden per year in the `[days]` section of `aoc.toml`.
+const DAY_COUNTS: [(u16, u8); 2] = [(2015, 25), (2025, 12)];
+
+/// A year in which an advent of code event took place (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// The year of the first advent of code event.
+    pub const FIRST: Self = Self(2015);
+
+    /// Creates a [`Year`] from the provided value if an event could have taken place in it,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < Self::FIRST.0 || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the number of puzzles of this year's event.
+    pub fn day_count(self) -> u8 {
+        if let Some(count) = config::day_count_overrides().get(&self.0) {
+            return *count;
+        }
+
+        DAY_COUNTS
+            .iter()
+            .rev()
+            .find(|(from, _)| self.0 >= *from)
+            .map_or(25, |(_, count)| *count)
+    }
+
+    /// Returns the configured event year. This is the value of `AOC_YEAR` if set, then the `year`
+    /// of `aoc.toml`, otherwise the year of the most recent event.
+    pub fn configured() -> Self {
+        std::env::var("AOC_YEAR")
+            .ok()
+            .and_then(|x| x.parse().ok())
+            .or_else(config::year)
+            .unwrap_or_else(Self::latest)
+    }
+
+    /// Returns the year of the most recent event (that may still be in progress).
+    pub fn latest() -> Self {
+        #[allow(clippy::cast_possible_wrap)]
+        let (year, month) = civil_from_days((unix_now() / 86_400) as i64);
+        let year = if month == 12 { year } else { year - 1 };
+        u16::try_from(year)
+            .ok()
+            .and_then(Self::new)
+            .unwrap_or(Self::FIRST)
+    }
+
+    /// Parses the year prefix of a bin name like `2024-05`.
+    pub const fn from_bin_name(name: &str) -> Option<Self> {
+        let bytes = name.as_bytes();
+        if bytes.len() < 5 || bytes[4] != b'-' {
+            return None;
+        }
+        match parse_digits(bytes, 4) {
+            Some(year) => Self::new(year),
+            None => None,
+        }
+    }
+
+    /// The `year` of `aoc.toml` (or `AOC_YEAR`) when the crate was compiled, see `build.rs`.
+    pub(crate) const fn compiled() -> Option<Self> {
+        match option_env!("AOC_YEAR") {
+            Some(year) if year.len() == 4 => match parse_digits(year.as_bytes(), 4) {
+                Some(year) => Self::new(year),
+                None => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// Parses the first `len` bytes as a decimal number in a const context.
+const fn parse_digits(bytes: &[u8], len: usize) -> Option<u16> {
+    let mut value: u16 = 0;
+    let mut i = 0;
+    while i < len {
+        let b = bytes[i];
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value * 10 + (b - b'0') as u16;
+        i += 1;
+    }
+    Some(value)
+}
+
+/// Converts days since the unix epoch to a (year, month) pair.
+/// See: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month)
+}
+
+/// Converts a date to days since the unix epoch, the inverse of [`civil_from_days`].
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let mp = i64::from(if month > 2 { month - 3 } else { month + 9 });
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting an event year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Year, civil_from_days, days_from_civil};
+
+    #[test]
+    fn counts_days_per_event() {
+        assert_eq!(Year::new(2015).unwrap().day_count(), 25);
+        assert_eq!(Year::new(2024).unwrap().day_count(), 25);
+        assert_eq!(Year::new(2025).unwrap().day_count(), 12);
+        assert_eq!(Year::new(2030).unwrap().day_count(), 12);
+    }
+
+    #[test]
+    fn parses_year_from_bin_name() {
+        assert_eq!(Year::from_bin_name("2024-05"), Year::new(2024));
+        assert_eq!(Year::from_bin_name("05"), None);
+        assert_eq!(Year::from_bin_name("1999-05"), None);
+        assert_eq!(Year::from_bin_name("advent_of_code"), None);
+    }
+
+    #[test]
+    fn converts_days_to_civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1));
+        // 2025-12-01
+        assert_eq!(civil_from_days(20_423), (2025, 12));
+        // 2024-02-29
+        assert_eq!(civil_from_days(19_782), (2024, 2));
+    }
+
+    #[test]
+    fn converts_civil_dates_to_days() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2025, 12, 1), 20_423);
+        assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+    }
+}